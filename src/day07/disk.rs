@@ -0,0 +1,98 @@
+use std::{fs::{self, File}, io, path::Path};
+
+use crate::utils::tree::NodeId;
+
+use super::{FileSystem, FileSystemObject};
+
+impl FileSystem {
+    /// Writes the filesystem out under `dest`, which must not exist yet. Files are
+    /// created sparse, so they have the recorded size without taking up the space. That
+    /// also means plain `du` reports next to nothing for them; compare against
+    /// `du --apparent-size -b` instead.
+    pub fn materialize(&self, dest: &Path) -> io::Result<()> {
+        fs::create_dir(dest)?;
+        self.materialize_children(self.tree.root(), dest)
+    }
+
+    fn materialize_children(&self, id: NodeId, dest: &Path) -> io::Result<()> {
+        for (name, child) in self.tree.children(id) {
+            let child_path = dest.join(name);
+            match self.tree.get(child) {
+                FileSystemObject::Directory => {
+                    fs::create_dir(&child_path)?;
+                    self.materialize_children(child, &child_path)?;
+                },
+                FileSystemObject::File { size } => {
+                    File::create(&child_path)?.set_len(*size as u64)?;
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Walks a real directory and writes down the `$ cd`/`$ ls` session that would explore
+/// it, in name order. Symlinks are skipped.
+pub fn transcript_from_dir(root: &Path) -> io::Result<Vec<String>> {
+    let mut lines = vec!["$ cd /".to_string()];
+    write_transcript(root, &mut lines)?;
+    Ok(lines)
+}
+
+fn write_transcript(dir: &Path, lines: &mut Vec<String>) -> io::Result<()> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if metadata.file_type().is_symlink() {
+            continue;
+        }
+        // the transcript format splits on spaces, so names have to be plain
+        let name = entry.file_name()
+            .into_string()
+            .ok()
+            .filter(|name| !name.contains(' '))
+            .ok_or_else(|| io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("can't write {:?} into a transcript", entry.path()),
+            ))?;
+        entries.push((name, metadata));
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    lines.push("$ ls".to_string());
+    for (name, metadata) in entries.iter() {
+        match metadata.is_dir() {
+            true => lines.push(format!("dir {}", name)),
+            false => lines.push(format!("{} {}", metadata.len(), name)),
+        }
+    }
+    for (name, _) in entries.iter().filter(|(_, metadata)| metadata.is_dir()) {
+        lines.push(format!("$ cd {}", name));
+        write_transcript(&dir.join(name), lines)?;
+        lines.push("$ cd ..".to_string());
+    }
+    Ok(())
+}
+
+/// scratch directory that is removed again even if the test fails half way
+#[cfg(test)]
+struct TempDir(std::path::PathBuf);
+
+#[cfg(test)]
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn test_materialize_round_trip() {
+    let original = super::load("res/day07/input.sample");
+    let dest = TempDir(std::env::temp_dir().join(format!("aoc-day07-{}", std::process::id())));
+    original.materialize(&dest.0).unwrap();
+    let transcript = transcript_from_dir(&dest.0).unwrap();
+    let replayed = FileSystem::from_transcript(transcript.into_iter(), super::ReplayMode::Strict).unwrap();
+    assert_eq!(replayed.listing(), original.listing());
+    assert_eq!(replayed.du_report(), original.du_report());
+}
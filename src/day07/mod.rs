@@ -1,126 +1,269 @@
-use std::{path::Path, rc::{Weak, Rc}, ops::Deref, collections::HashMap};
+use std::{path::Path, fmt::{Display, Write}};
 
-use crate::utils::{self, AOCResult, AOCError};
+use crate::utils::{self, parse_usize, tree::{Tree, NodeId, Weighted}};
 
-struct FileSystem {
-    working_directory: Rc<FileSystemObject>,
-    root_directory: Rc<FileSystemObject>,
+mod disk;
+
+pub use disk::transcript_from_dir;
+
+const TOTAL_DISK_SPACE: usize = 70000000;
+const SPACE_NEEDED_FOR_UPDATE: usize = 30000000;
+
+/// How to treat a transcript that `cd`s somewhere it never saw in an `ls`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReplayMode {
+    /// unseen directories are an error
+    Strict,
+    /// unseen directories get created on the spot
+    Lenient,
 }
 
-impl FileSystem {
-    fn cd<S: AsRef<str>>(&mut self, arg: S) {
-        match arg.as_ref() {
-            ".." => {
-                self.working_directory = self.working_directory
-                    .get_parent()
-                    .expect("working directory has a parent since it exists");
-            },
-            "/" => {
-                self.working_directory = self.root_directory.clone();
-            }
-            dir => {
-                self.working_directory = self.working_directory.get_child(dir).unwrap();
-            }
+#[derive(Debug, PartialEq, Eq)]
+pub enum TranscriptErrorKind {
+    UnknownDirectory(String),
+    CdIntoFile(String),
+    CdAboveRoot,
+    UnknownCommand(String),
+    /// a listing line that doesn't follow an `ls`
+    ListingWithoutLs,
+    /// the same name showed up as a file in one listing and a directory in another
+    FileDirConflict(String),
+    SizeConflict { name: String, old_size: usize, new_size: usize },
+    Unparseable,
+}
+
+/// something wrong with a transcript, along with the (1 indexed) line it happened on
+#[derive(Debug)]
+pub struct TranscriptError {
+    pub line_number: usize,
+    pub line: String,
+    pub kind: TranscriptErrorKind,
+}
+
+impl Display for TranscriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let problem = match &self.kind {
+            TranscriptErrorKind::UnknownDirectory(name) => format!("no such directory {}", name),
+            TranscriptErrorKind::CdIntoFile(name) => format!("can't cd into file {}", name),
+            TranscriptErrorKind::CdAboveRoot => "can't cd above the root directory".to_string(),
+            TranscriptErrorKind::UnknownCommand(command) => format!("unknown command {}", command),
+            TranscriptErrorKind::ListingWithoutLs => "listing output without an ls".to_string(),
+            TranscriptErrorKind::FileDirConflict(name) => format!("{} was listed as both a file and a directory", name),
+            TranscriptErrorKind::SizeConflict { name, old_size, new_size } =>
+                format!("{} was listed with size {} and then {}", name, old_size, new_size),
+            TranscriptErrorKind::Unparseable => "can't understand line".to_string(),
         };
+        write!(f, "line {}: {} ('{}')", self.line_number, problem, self.line)
     }
 }
 
-enum FileSystemObject {
-    Directory {contents: HashMap<String, Rc<FileSystemObject>>, parent: Weak<FileSystemObject>},
-    File {size: usize, parent: Weak<FileSystemObject>},
+pub struct FileSystem {
+    tree: Tree<FileSystemObject>,
+    working_directory: NodeId,
+    mode: ReplayMode,
+    in_listing: bool,
 }
 
-impl FileSystemObject {
+impl FileSystem {
+    fn new(mode: ReplayMode) -> Self {
+        let tree = Tree::new(FileSystemObject::Directory);
+        let working_directory = tree.root();
+        Self { tree, working_directory, mode, in_listing: false }
+    }
 
-    fn new_dir(parent: &Rc<FileSystemObject>) -> FileSystemObject {
-        FileSystemObject::Directory { 
-            contents: HashMap::new(), 
-            parent: Rc::<FileSystemObject>::downgrade(parent) 
+    fn from_transcript(lines: impl Iterator<Item = String>, mode: ReplayMode) -> Result<Self, TranscriptError> {
+        let mut file_system = Self::new(mode);
+        for (ix, line) in lines.enumerate() {
+            file_system.apply_line(&line)
+                .map_err(|kind| TranscriptError { line_number: ix + 1, line, kind })?;
         }
+        Ok(file_system)
     }
 
-    fn get_parent(&self) -> Option<Rc<FileSystemObject>> {
-        let parent = match self {
-            FileSystemObject::Directory { parent, ..} => parent,
-            FileSystemObject::File { parent, .. } => parent,
-        };
-        match parent.deref().upgrade() {
-            Some(parent) => Some(parent),
-            None => None,
+    fn apply_line(&mut self, line: &str) -> Result<(), TranscriptErrorKind> {
+        let parts: Vec<&str> = line.split(' ').collect();
+        match parts.as_slice() {
+            ["$", "cd", arg] => {
+                self.in_listing = false;
+                self.cd(arg)
+            },
+            ["$", "ls"] => {
+                self.in_listing = true;
+                Ok(())
+            },
+            ["$", command, ..] => Err(TranscriptErrorKind::UnknownCommand(command.to_string())),
+            [_, _] if !self.in_listing => Err(TranscriptErrorKind::ListingWithoutLs),
+            ["dir", name] => self.add_to_working_directory(FileSystemObject::Directory, name),
+            [size, name] => {
+                let size = parse_usize(size).ok_or(TranscriptErrorKind::Unparseable)?;
+                self.add_to_working_directory(FileSystemObject::File { size }, name)
+            },
+            _ => Err(TranscriptErrorKind::Unparseable),
         }
     }
 
-    fn add_child(&mut self, object: FileSystemObject, name: String) -> AOCResult<()> {
-        match self {
-            FileSystemObject::Directory { contents, .. } => {
-                if !contents.contains_key(&name) {
-                    contents.insert(name, Rc::new(object));
-                }
-                return Ok(())
+    fn cd(&mut self, arg: &str) -> Result<(), TranscriptErrorKind> {
+        let start = match arg.starts_with('/') {
+            true => self.tree.root(),
+            false => self.working_directory,
+        };
+        let mut target = start;
+        for part in arg.split('/').filter(|part| !part.is_empty() && *part != ".") {
+            target = match (part, self.tree.child(target, part)) {
+                ("..", _) => self.tree.parent(target).ok_or(TranscriptErrorKind::CdAboveRoot)?,
+                (_, Some(child)) => child,
+                (_, None) if self.mode == ReplayMode::Lenient => {
+                    self.tree.add_child(target, part, FileSystemObject::Directory)
+                },
+                (_, None) => return Err(TranscriptErrorKind::UnknownDirectory(part.to_string())),
+            };
+            if let FileSystemObject::File { .. } = self.tree.get(target) {
+                return Err(TranscriptErrorKind::CdIntoFile(part.to_string()));
             }
-            FileSystemObject::File {..} => Err(AOCError::new("file can't have children")),
         }
+        self.working_directory = target;
+        Ok(())
     }
 
-    fn get_child<S: AsRef<str>>(&self, name: S) -> AOCResult<Rc<FileSystemObject>> {
-        match self {
-            FileSystemObject::Directory { contents, .. } => {
-                let object = contents.get(name.as_ref());
-                match object {
-                    Some(object) => match object.deref() {
-                        FileSystemObject::Directory {..} => Ok(object.clone()),
-                        FileSystemObject::File {..} => Err(AOCError::new("Can't cd into a file")),
+    /// adds something from an `ls` listing, checking it against anything we were told before
+    fn add_to_working_directory(&mut self, object: FileSystemObject, name: &str) -> Result<(), TranscriptErrorKind> {
+        match self.tree.child(self.working_directory, name).map(|id| self.tree.get(id)) {
+            None => {
+                self.tree.add_child(self.working_directory, name, object);
+                Ok(())
+            },
+            Some(existing) => match (existing, object) {
+                (FileSystemObject::Directory, FileSystemObject::Directory) => Ok(()),
+                (FileSystemObject::File { size: old_size }, FileSystemObject::File { size: new_size }) => {
+                    match *old_size == new_size {
+                        true => Ok(()),
+                        false => Err(TranscriptErrorKind::SizeConflict { name: name.to_string(), old_size: *old_size, new_size }),
                     }
-                    None => Err(AOCError::new(format!("no such directory {}", name.as_ref()))),
-                }
+                },
+                _ => Err(TranscriptErrorKind::FileDirConflict(name.to_string())),
             },
-            FileSystemObject::File {..} => Err(AOCError::new("file has no subdirectories")),
         }
     }
 
-    fn size(&self) -> usize {
-        match self {
-            FileSystemObject::Directory { contents, .. } => contents
-                .values()
-                .map(|obj| obj.size())
-                .sum(),
-            FileSystemObject::File { size, .. } => *size,
+    fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.tree.ids()
+            .filter(|id| matches!(self.tree.get(*id), FileSystemObject::Directory))
+    }
+
+    fn size(&self, id: NodeId) -> usize {
+        self.tree.subtree_weight(id)
+    }
+
+    /// total size of whatever lives at an absolute path like `/a/e`
+    pub fn size_of(&self, path: &str) -> Option<usize> {
+        self.tree.resolve(self.tree.root(), path).map(|id| self.size(id))
+    }
+
+    /// the indented `- / (dir)` listing from the puzzle statement
+    pub fn listing(&self) -> String {
+        let mut out = String::new();
+        self.write_listing(self.tree.root(), 0, &mut out);
+        out
+    }
+
+    fn write_listing(&self, id: NodeId, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        let name = self.tree.name(id);
+        match self.tree.get(id) {
+            FileSystemObject::Directory => writeln!(out, "{}- {} (dir)", indent, name),
+            FileSystemObject::File { size } => writeln!(out, "{}- {} (file, size={})", indent, name, size),
+        }.expect("writing to a string can't fail");
+        for (_, child) in self.tree.children(id) {
+            self.write_listing(child, depth + 1, out);
         }
     }
 
+    /// like `du -a`: every file and directory with its size, biggest first
+    pub fn du_report(&self) -> String {
+        let mut entries: Vec<(usize, String)> = self.tree.ids()
+            .map(|id| (self.size(id), self.tree.path(id)))
+            .collect();
+        entries.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        entries.into_iter()
+            .map(|(size, path)| format!("{}\t{}\n", size, path))
+            .collect()
+    }
+
+    /// every directory whose total size is at most `max_size`, as (path, size)
+    pub fn directories_at_most(&self, max_size: usize) -> Vec<(String, usize)> {
+        self.directories()
+            .filter(|id| self.size(*id) <= max_size)
+            .map(|id| (self.tree.path(id), self.size(id)))
+            .collect()
+    }
+
+    /// the smallest directory that would free up at least `min_size` if deleted
+    pub fn smallest_directory_at_least(&self, min_size: usize) -> Option<(String, usize)> {
+        self.directories()
+            .filter(|id| self.size(*id) >= min_size)
+            .min_by_key(|id| self.size(*id))
+            .map(|id| (self.tree.path(id), self.size(id)))
+    }
+
+    /// how much has to be deleted before the update fits on the disk
+    pub fn space_to_free(&self) -> usize {
+        let used = self.size(self.tree.root());
+        (used + SPACE_NEEDED_FOR_UPDATE).saturating_sub(TOTAL_DISK_SPACE)
+    }
 }
 
-fn apply_command(line: String, file_system: &mut FileSystem) {
-    let mut parts = line.split(" ");
-    let size_or_mark = parts.next().unwrap();
-    match size_or_mark {
-        "$" => {
-            let command = parts.next().unwrap();
-            if command == "cd" {
-                let arg = parts.next().unwrap();
-                file_system.cd(arg);
-            }
-            // ignore ls, its useless
-        },
-        "dir" => {
-            let name = parts.next().unwrap();
-            file_system.working_directory.add_child(
-                FileSystemObject::new_dir(&file_system.working_directory),
-                name.to_owned()
-            );
-        }
-        _ => {
+enum FileSystemObject {
+    Directory,
+    File {size: usize},
+}
 
+impl Weighted for FileSystemObject {
+    fn weight(&self) -> usize {
+        match self {
+            FileSystemObject::Directory => 0,
+            FileSystemObject::File { size } => *size,
         }
     }
 }
 
+pub fn load_with_mode<P: AsRef<Path>>(path: P, mode: ReplayMode) -> Result<FileSystem, TranscriptError> {
+    let lines = utils::read_input(path);
+    FileSystem::from_transcript(lines, mode)
+}
+
+pub fn load<P: AsRef<Path>>(path: P) -> FileSystem {
+    load_with_mode(path, ReplayMode::Strict).unwrap_or_else(|err| panic!("{}", err))
+}
+
 pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
-    let mut lines = utils::read_input(path);
-    "n/a".to_string()
+    let total: usize = load(path).directories_at_most(100000)
+        .into_iter()
+        .map(|(_, size)| size)
+        .sum();
+    total.to_string()
 }
 
 pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
-    let mut lines = utils::read_input(path);
-    "n/a".to_string()
-}
\ No newline at end of file
+    let file_system = load(path);
+    let (_, size) = file_system.smallest_directory_at_least(file_system.space_to_free())
+        .expect("the root directory always frees enough");
+    size.to_string()
+}
+
+#[test]
+fn test_transcript_validation() {
+    let transcript = |lines: &[&str]| lines.iter().map(|line| line.to_string()).collect::<Vec<_>>().into_iter();
+    let unseen = transcript(&["$ cd /", "$ cd a", "$ ls", "10 b"]);
+    let err = FileSystem::from_transcript(unseen.clone(), ReplayMode::Strict).err().unwrap();
+    assert_eq!(err.line_number, 2);
+    assert_eq!(err.kind, TranscriptErrorKind::UnknownDirectory("a".to_string()));
+    let file_system = FileSystem::from_transcript(unseen, ReplayMode::Lenient).unwrap();
+    assert_eq!(file_system.size_of("/a"), Some(10));
+
+    let changed_size = transcript(&["$ ls", "10 b", "$ ls", "11 b"]);
+    let err = FileSystem::from_transcript(changed_size, ReplayMode::Strict).err().unwrap();
+    assert_eq!(err.line_number, 4);
+    let file_then_dir = transcript(&["$ ls", "10 b", "$ ls", "dir b"]);
+    let err = FileSystem::from_transcript(file_then_dir, ReplayMode::Lenient).err().unwrap();
+    assert_eq!(err.kind, TranscriptErrorKind::FileDirConflict("b".to_string()));
+}
@@ -0,0 +1,94 @@
+use std::{fs::File, io::{self, Write as IoWrite}, fmt::Write, path::Path};
+
+use crate::utils::grid::{Grid, ScanDirection};
+
+use super::{Forest, Sightlines, is_visible, scenic_score, sightlines};
+
+const ANSI_RESET: &str = "\x1b[0m";
+const ANSI_VISIBLE: &str = "\x1b[1;32m";
+const ANSI_HIDDEN: &str = "\x1b[2m";
+
+/// black -> red -> yellow -> white as `t` goes from 0 to 1
+fn heat_colour(t: f64) -> (u8, u8, u8) {
+    let t = t.clamp(0.0, 1.0);
+    let channel = |start: f64| (((t - start) * 3.0).clamp(0.0, 1.0) * 255.0) as u8;
+    (channel(0.0), channel(1.0 / 3.0), channel(2.0 / 3.0))
+}
+
+/// scenic scores squashed into 0..=1. most scores are tiny next to the best one, so a
+/// square root keeps the heatmap from being all black
+fn normalized_scores(lines: &Grid<Sightlines>) -> Vec<f64> {
+    let max_score = lines.iter().map(scenic_score).max().unwrap_or(0).max(1) as f64;
+    lines.iter()
+        .map(|sightlines| (scenic_score(sightlines) as f64 / max_score).sqrt())
+        .collect()
+}
+
+/// the forest with the trees that can be seen from outside highlighted
+pub fn render_visibility(forest: &Forest) -> String {
+    let lines = sightlines(forest);
+    let mut out = String::new();
+    for row in 0..forest.height() {
+        for col in 0..forest.width() {
+            let colour = match is_visible(lines.get(row, col)) {
+                true => ANSI_VISIBLE,
+                false => ANSI_HIDDEN,
+            };
+            out.push_str(&format!("{}{}{}", colour, forest.get(row, col), ANSI_RESET));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// the forest coloured by scenic score, brightest is best
+pub fn render_heatmap(forest: &Forest) -> String {
+    let scores = normalized_scores(&sightlines(forest));
+    let mut out = String::new();
+    for row in 0..forest.height() {
+        for col in 0..forest.width() {
+            let (r, g, b) = heat_colour(scores[row * forest.width() + col]);
+            out.push_str(&format!("\x1b[48;2;{};{};{}m{}{}", r, g, b, forest.get(row, col), ANSI_RESET));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// writes the scenic score heatmap as a binary PPM, with each tree drawn as a `scale` x `scale` square
+pub fn write_heatmap_ppm<P: AsRef<Path>>(forest: &Forest, out_path: P, scale: usize) -> io::Result<()> {
+    let scores = normalized_scores(&sightlines(forest));
+    let (width, height) = (forest.width() * scale, forest.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let (r, g, b) = heat_colour(scores[(y / scale) * forest.width() + x / scale]);
+            pixels.extend([r, g, b]);
+        }
+    }
+    let mut file = File::create(out_path)?;
+    write!(file, "P6\n{} {}\n255\n", width, height)?;
+    file.write_all(&pixels)
+}
+
+/// the `k` best trees by scenic score, with how far they can see in each direction
+pub fn top_scenic_spots(forest: &Forest, k: usize) -> String {
+    let lines = sightlines(forest);
+    let mut spots: Vec<(usize, usize, &Sightlines)> = (0..forest.height())
+        .flat_map(|row| (0..forest.width()).map(move |col| (row, col)))
+        .map(|(row, col)| (row, col, lines.get(row, col)))
+        .collect();
+    spots.sort_by_key(|(row, col, sightlines)| (std::cmp::Reverse(scenic_score(sightlines)), *row, *col));
+
+    let mut out = String::new();
+    let directions: Vec<String> = ScanDirection::ALL.iter().map(|d| format!("{:?}", d).to_lowercase()).collect();
+    writeln!(out, "rank\trow\tcol\theight\tscore\t{}", directions.join("\t")).unwrap();
+    for (rank, (row, col, sightlines)) in spots.into_iter().take(k).enumerate() {
+        let distances: Vec<String> = sightlines.iter().map(|sightline| sightline.distance.to_string()).collect();
+        writeln!(
+            out, "{}\t{}\t{}\t{}\t{}\t{}",
+            rank + 1, row, col, forest.get(row, col), scenic_score(sightlines), distances.join("\t"),
+        ).unwrap();
+    }
+    out
+}
@@ -0,0 +1,234 @@
+use std::{collections::HashSet, fmt::Write, path::Path, time::Instant};
+
+use crate::utils::{AOCResult, lcg::Lcg};
+
+use super::{Point, Displacement, PointLike, Motion, Direction, Snake, FollowRule, read_motions};
+
+/// Set of points backed by a bitmap over a bounding box, which doubles in size whenever
+/// a point lands outside of it.
+struct VisitedBitmap {
+    min: Point,
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl VisitedBitmap {
+    fn new() -> Self {
+        let (width, height) = (64, 64);
+        Self { min: (-32, -32), width, height, words: vec![0; width * height / 64], len: 0 }
+    }
+
+    fn contains_in_bounds(&self, point: Point) -> bool {
+        point.0 >= self.min.0 && point.1 >= self.min.1
+            && ((point.0 - self.min.0) as usize) < self.width
+            && ((point.1 - self.min.1) as usize) < self.height
+    }
+
+    fn bit(&self, point: Point) -> (usize, u64) {
+        let ix = (point.1 - self.min.1) as usize * self.width + (point.0 - self.min.0) as usize;
+        (ix / 64, 1 << (ix % 64))
+    }
+
+    /// Grows the bitmap until `point` fits, copying over everything already set. Each
+    /// growth doubles one dimension, adding the new space on the side `point` is on, so
+    /// old rows always land on a word boundary and can be copied a word at a time.
+    fn grow_to_fit(&mut self, point: Point) {
+        let (mut min, mut width, mut height) = (self.min, self.width, self.height);
+        let (mut x_offset, mut y_offset) = (0, 0);
+        while point.0 < min.0 || point.0 >= min.0 + width as i32 {
+            if point.0 < min.0 {
+                min.0 -= width as i32;
+                x_offset += width;
+            }
+            width *= 2;
+        }
+        while point.1 < min.1 || point.1 >= min.1 + height as i32 {
+            if point.1 < min.1 {
+                min.1 -= height as i32;
+                y_offset += height;
+            }
+            height *= 2;
+        }
+        let (old_row_words, new_row_words) = (self.width / 64, width / 64);
+        let mut words = vec![0; new_row_words * height];
+        for (y, row) in self.words.chunks(old_row_words).enumerate() {
+            let start = (y + y_offset) * new_row_words + x_offset / 64;
+            words[start..start + old_row_words].copy_from_slice(row);
+        }
+        *self = Self { min, width, height, words, len: self.len };
+    }
+
+    fn insert(&mut self, point: Point) {
+        if !self.contains_in_bounds(point) {
+            self.grow_to_fit(point);
+        }
+        let (word, mask) = self.bit(point);
+        if self.words[word] & mask == 0 {
+            self.words[word] |= mask;
+            self.len += 1;
+        }
+    }
+
+    /// inserts the `amount` points after `start` going in steps of `step`
+    fn insert_line(&mut self, start: Point, step: Displacement, amount: usize) {
+        let amount = amount as i32;
+        // growing once for the far end means the whole line fits
+        self.insert(start.add((step.0 * amount, step.1 * amount)));
+        for i in 1..amount {
+            self.insert(start.add((step.0 * i, step.1 * i)));
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl FollowRule {
+    /// How far apart consecutive knots sit once the rope is pulled taut, if a taut rope
+    /// just slides along behind the head under this rule.
+    fn taut_spacing(&self) -> Option<i32> {
+        match self {
+            FollowRule::Slack(slack) => Some(*slack),
+            FollowRule::Orthogonal => Some(1),
+            FollowRule::Elastic(_) => None,
+        }
+    }
+}
+
+/// Rope simulation that only steps knot by knot until the rope is pulled taut behind the
+/// head, and then slides the whole rope along for the rest of the motion in one go.
+struct FastRope {
+    snake: Snake,
+    visited: VisitedBitmap,
+}
+
+impl FastRope {
+    fn new(length: usize, rule: FollowRule) -> AOCResult<Self> {
+        let mut visited = VisitedBitmap::new();
+        visited.insert((0, 0));
+        Ok(Self { snake: Snake::with_rule(length, rule)?, visited })
+    }
+
+    fn tail(&self) -> Point {
+        *self.snake.segments.last().expect("snake has a tail")
+    }
+
+    /// true if every knot sits exactly `spacing` steps behind the one before it
+    fn is_taut(&self, step: Displacement, spacing: i32) -> bool {
+        let behind = (-step.0 * spacing, -step.1 * spacing);
+        self.snake.segments
+            .windows(2)
+            .all(|pair| pair[1] == pair[0].add(behind))
+    }
+
+    fn apply(&mut self, motion: Motion) {
+        let step = motion.direction.displacement();
+        let taut_spacing = self.snake.rule.taut_spacing();
+        // a rope that's pulled straight for long enough ends up taut, so past the start of
+        // the motion there's no point checking until then
+        let steps_until_taut = taut_spacing.map(|spacing| (self.snake.segments.len() - 1) * spacing as usize);
+        let mut remaining = motion.amount;
+        while remaining > 0 {
+            let steps_taken = motion.amount - remaining;
+            let worth_checking = steps_until_taut.is_some_and(|steps| steps_taken == 0 || steps_taken >= steps);
+            if worth_checking && self.is_taut(step, taut_spacing.expect("checked above")) {
+                self.visited.insert_line(self.tail(), step, remaining);
+                let slide = (step.0 * remaining as i32, step.1 * remaining as i32);
+                for knot in self.snake.segments.iter_mut() {
+                    *knot = knot.add(slide);
+                }
+                return;
+            }
+            self.snake.move_one(&motion.direction);
+            self.visited.insert(self.tail());
+            remaining -= 1;
+        }
+    }
+}
+
+fn fast_tail_positions(motions: impl Iterator<Item = Motion>, length: usize, rule: FollowRule) -> AOCResult<usize> {
+    let mut rope = FastRope::new(length, rule)?;
+    for motion in motions {
+        rope.apply(motion);
+    }
+    Ok(rope.visited.len())
+}
+
+/// same as the part answers, but run on the fast engine
+pub fn fast_num_tail_positions<P: AsRef<Path>>(path: P, length: usize, rule: FollowRule) -> AOCResult<usize> {
+    fast_tail_positions(read_motions(path), length, rule)
+}
+
+/// Long random walk where motions are usually much longer than the rope, which is the
+/// case the fast engine is built for. The head turns back whenever it would stray more
+/// than `10 * max_amount` from the start, so the visited area stays compact like the real
+/// inputs. Seeded the same every time so runs are repeatable.
+fn synthetic_motions(num_motions: usize, max_amount: usize) -> Vec<Motion> {
+    const DIRECTIONS: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+    let radius = 10 * max_amount as i32;
+    let mut lcg = Lcg::new(0x2022_0009);
+    let mut head: Point = (0, 0);
+    (0..num_motions)
+        .map(|_| {
+            let amount = 1 + lcg.next() % max_amount;
+            let end_of = |ix: usize| {
+                let (dx, dy) = DIRECTIONS[ix].displacement();
+                head.add((dx * amount as i32, dy * amount as i32))
+            };
+            let mut ix = lcg.next() % 4;
+            if end_of(ix).0.abs() > radius || end_of(ix).1.abs() > radius {
+                // directions come in opposite pairs
+                ix ^= 1;
+            }
+            head = end_of(ix);
+            Motion { direction: DIRECTIONS[ix], amount }
+        })
+        .collect()
+}
+
+/// Times the step-by-step `HashSet` engine against the fast one on a synthetic input.
+pub fn benchmark(num_motions: usize, max_amount: usize, length: usize) -> AOCResult<String> {
+    let motions = synthetic_motions(num_motions, max_amount);
+    let num_steps: usize = motions.iter().map(|motion| motion.amount).sum();
+    let mut out = String::new();
+    writeln!(out, "{} motions, {} steps, {} knots", num_motions, num_steps, length).unwrap();
+
+    let start = Instant::now();
+    let slow: HashSet<Point> = Snake::new(length)?
+        .simulate(motions.iter().copied())
+        .map(|knots| *knots.last().expect("snake has a tail"))
+        .chain(std::iter::once((0, 0)))
+        .collect();
+    writeln!(out, "step by step: {} tail positions in {:?}", slow.len(), start.elapsed()).unwrap();
+
+    let start = Instant::now();
+    let fast = fast_tail_positions(motions.iter().copied(), length, FollowRule::STANDARD)?;
+    writeln!(out, "run length  : {} tail positions in {:?}", fast, start.elapsed()).unwrap();
+    Ok(out)
+}
+
+#[test]
+fn test_fast_engine_matches_step_by_step() {
+    let rules = [FollowRule::STANDARD, FollowRule::Slack(3), FollowRule::Orthogonal, FollowRule::Elastic(2)];
+    let motions = synthetic_motions(300, 40);
+    let diagonal_motions: Vec<Motion> = motions.iter()
+        .enumerate()
+        .map(|(i, motion)| Motion { direction: [Direction::UpLeft, Direction::DownRight, Direction::Up][i % 3], ..*motion })
+        .collect();
+    for rule in rules {
+        for length in [2, 10, 25] {
+            for motions in [&motions, &diagonal_motions] {
+                let slow: HashSet<Point> = Snake::with_rule(length, rule)
+                    .unwrap()
+                    .simulate(motions.iter().copied())
+                    .map(|knots| *knots.last().unwrap())
+                    .chain(std::iter::once((0, 0)))
+                    .collect();
+                assert_eq!(fast_tail_positions(motions.iter().copied(), length, rule).unwrap(), slow.len());
+            }
+        }
+    }
+}
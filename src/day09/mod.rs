@@ -1,6 +1,12 @@
-use std::{path::Path, collections::HashSet};
+use std::{path::Path, collections::HashSet, str::FromStr};
 
-use crate::utils;
+use crate::utils::{self, AOCError, AOCResult};
+
+mod render;
+mod fast;
+
+pub use render::{RenderMode, Viewport, render_simulation};
+pub use fast::{fast_num_tail_positions, benchmark};
 
 
 type Point = (i32, i32);
@@ -12,6 +18,7 @@ trait PointLike {
 }
 
 trait DisplacementLike {
+    fn chebyshev(&self) -> i32;
     fn is_adjacent(&self) -> bool;
     fn is_cardinal(&self) -> bool;
     fn normalize(&self) -> Displacement;
@@ -29,9 +36,14 @@ impl PointLike for Point {
 }
 
 impl DisplacementLike for Displacement {
+    /// how many king moves it takes to cover the displacement
+    fn chebyshev(&self) -> i32 {
+        self.0.abs().max(self.1.abs())
+    }
+
     /// tells you if the displacement is between adjacent coordinates
     fn is_adjacent(&self) -> bool {
-        self.0.abs().max(self.1.abs()) <= 1
+        self.chebyshev() <= 1
     }
 
     fn is_cardinal(&self) -> bool {
@@ -48,18 +60,37 @@ impl DisplacementLike for Displacement {
     }
 }
 
+#[derive(Clone, Copy, Debug)]
 enum Direction {
-    Up, Down, Left, Right
+    Up, Down, Left, Right,
+    UpLeft, UpRight, DownLeft, DownRight,
 }
 
 impl Direction {
-    fn char_to_direction(c: char) -> Self {
-        match c {
-            'U' => Direction::Up,
-            'D' => Direction::Down,
-            'L' => Direction::Left,
-            'R' => Direction::Right,
-            _ => panic!("invalid direction {:?}", c),
+    fn parse(s: &str) -> Self {
+        match s {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            "UL" => Direction::UpLeft,
+            "UR" => Direction::UpRight,
+            "DL" => Direction::DownLeft,
+            "DR" => Direction::DownRight,
+            _ => panic!("invalid direction {:?}", s),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Direction::Up => "U",
+            Direction::Down => "D",
+            Direction::Left => "L",
+            Direction::Right => "R",
+            Direction::UpLeft => "UL",
+            Direction::UpRight => "UR",
+            Direction::DownLeft => "DL",
+            Direction::DownRight => "DR",
         }
     }
 
@@ -69,23 +100,129 @@ impl Direction {
             Direction::Down => (0, -1),
             Direction::Left => (-1, 0),
             Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, 1),
+            Direction::UpRight => (1, 1),
+            Direction::DownLeft => (-1, -1),
+            Direction::DownRight => (1, -1),
+        }
+    }
+}
+
+/// How a knot follows the knot in front of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FollowRule {
+    /// once the knot ahead is more than this many king moves away, take one step
+    /// (diagonally if need be) towards it. `Slack(1)` is the puzzle's rule
+    Slack(i32),
+    /// catch up using only horizontal and vertical steps, along the longer axis first,
+    /// until touching again
+    Orthogonal,
+    /// stretch up to this many king moves without moving, then snap back to touching
+    Elastic(i32),
+}
+
+impl FollowRule {
+    pub const STANDARD: FollowRule = FollowRule::Slack(1);
+
+    /// where a knot at `curr` ends up after the knot ahead of it moved to `ahead`
+    fn follow(&self, curr: Point, ahead: Point) -> Point {
+        let disp = curr.displacement_to(ahead);
+        match self {
+            FollowRule::Slack(slack) if disp.chebyshev() > *slack => curr.add(disp.normalize()),
+            FollowRule::Slack(_) => curr,
+            FollowRule::Orthogonal => {
+                let mut curr = curr;
+                let mut disp = disp;
+                while !disp.is_adjacent() {
+                    let step = match disp.0.abs() >= disp.1.abs() {
+                        true => (disp.0.signum(), 0),
+                        false => (0, disp.1.signum()),
+                    };
+                    curr = curr.add(step);
+                    disp = curr.displacement_to(ahead);
+                }
+                curr
+            },
+            FollowRule::Elastic(stretch) if disp.chebyshev() > *stretch => {
+                let mut curr = curr;
+                while !curr.displacement_to(ahead).is_adjacent() {
+                    curr = curr.add(curr.displacement_to(ahead).normalize());
+                }
+                curr
+            },
+            FollowRule::Elastic(_) => curr,
+        }
+    }
+}
+
+impl FromStr for FollowRule {
+    type Err = AOCError;
+
+    /// parses `standard`, `orthogonal`, `slack:N` or `elastic:N`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad_rule = || AOCError::new(format!("unknown follow rule {}, expected standard, orthogonal, slack:N or elastic:N", s));
+        let amount = |amount: &str| utils::parse_i32(amount).filter(|amount| *amount >= 1).ok_or_else(bad_rule);
+        match s.split_once(':') {
+            None if s == "standard" => Ok(FollowRule::STANDARD),
+            None if s == "orthogonal" => Ok(FollowRule::Orthogonal),
+            Some(("slack", n)) => Ok(FollowRule::Slack(amount(n)?)),
+            Some(("elastic", n)) => Ok(FollowRule::Elastic(amount(n)?)),
+            _ => Err(bad_rule()),
         }
     }
 }
 
+#[derive(Clone, Copy, Debug)]
+struct Motion {
+    direction: Direction,
+    amount: usize,
+}
+
+impl Motion {
+    fn parse(line: String) -> Self {
+        let mut parts = line.split(' ');
+        let direction = Direction::parse(parts.next().unwrap());
+        let amount = utils::parse_usize(parts.next().unwrap()).unwrap();
+        Self { direction, amount }
+    }
+}
+
+fn read_motions<P: AsRef<Path>>(path: P) -> impl Iterator<Item = Motion> {
+    utils::read_input(path).map(Motion::parse)
+}
+
 struct Snake {
-    segments: Vec<Point>
+    segments: Vec<Point>,
+    rule: FollowRule,
 }
 
 impl Snake {
 
-    fn new(length: usize) -> Self {
-        assert!(length >= 2);
+    fn new(length: usize) -> AOCResult<Self> {
+        Self::with_rule(length, FollowRule::STANDARD)
+    }
+
+    /// a rope needs a head and a tail, so `length` has to be at least 2
+    fn with_rule(length: usize, rule: FollowRule) -> AOCResult<Self> {
+        if length < 2 {
+            return Err(AOCError::new(format!("a rope needs at least 2 knots, got {}", length)));
+        }
         let mut segments = Vec::new();
-        for i in 0..length {
+        for _ in 0..length {
             segments.push((0, 0));
         }
-        Self { segments: segments }
+        Ok(Self { segments, rule })
+    }
+
+    /// Moves the snake through `motions` one unit step at a time, yielding the position
+    /// of every knot (head first) after each step.
+    fn simulate(mut self, motions: impl Iterator<Item = Motion>) -> impl Iterator<Item = Vec<Point>> {
+        motions
+            .flat_map(|motion| std::iter::repeat_n(motion.direction, motion.amount))
+            .map(move |direction| {
+                self.move_one(&direction);
+                self.segments.clone()
+            })
     }
 
     fn move_one(&mut self, direction: &Direction) {
@@ -95,52 +232,62 @@ impl Snake {
     }
 
     fn move_tails(&mut self) {
-        let mut prev = None;
-        for i in 0..self.segments.len() {
-            let mut curr = self.segments[i];
-            match prev {
-                Some(prev) => {
-                    let disp_to_prev = curr.displacement_to(prev);
-                    if !disp_to_prev.is_adjacent() {
-                        curr = curr.add(disp_to_prev.normalize());
-                        self.segments[i] = curr;
-                    }
-                },
-                None => {},
-            }
-            prev = Some(curr);
+        for i in 1..self.segments.len() {
+            self.segments[i] = self.rule.follow(self.segments[i], self.segments[i - 1]);
         }
     }
 }
 
-pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
-    let lines = utils::read_input(path);
-    let mut snake = Snake::new(2);
-    let mut set: HashSet<Point> = HashSet::new();
-    for line in lines {
-        let mut parts = line.split(" ");
-        let direction = Direction::char_to_direction(parts.next().unwrap().chars().next().unwrap());
-        let amount = utils::parse_int(parts.next().unwrap()).unwrap();
-        for _ in 0..amount {
-            snake.move_one(&direction);
-            set.insert(snake.segments.last().unwrap().clone());
+fn num_tail_positions<P: AsRef<Path>>(path: P, length: usize) -> usize {
+    let set: HashSet<Point> = Snake::new(length)
+        .expect("the puzzle ropes are long enough")
+        .simulate(read_motions(path))
+        .map(|knots| *knots.last().expect("snake has a tail"))
+        .collect();
+    set.len()
+}
+
+/// how many distinct cells each knot (head first) visits, including the start
+pub fn visited_per_knot<P: AsRef<Path>>(path: P, length: usize, rule: FollowRule) -> AOCResult<Vec<usize>> {
+    let snake = Snake::with_rule(length, rule)?;
+    let mut visited: Vec<HashSet<Point>> = (0..length)
+        .map(|_| HashSet::from([(0, 0)]))
+        .collect();
+    for knots in snake.simulate(read_motions(path)) {
+        for (set, knot) in visited.iter_mut().zip(knots) {
+            set.insert(knot);
         }
     }
-    set.len().to_string()
+    Ok(visited.iter().map(|set| set.len()).collect())
+}
+
+pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
+    num_tail_positions(path, 2).to_string()
 }
 
 pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
-    let lines = utils::read_input(path);
-    let mut snake = Snake::new(10);
-    let mut set: HashSet<Point> = HashSet::new();
-    for line in lines {
-        let mut parts = line.split(" ");
-        let direction = Direction::char_to_direction(parts.next().unwrap().chars().next().unwrap());
-        let amount = utils::parse_int(parts.next().unwrap()).unwrap();
-        for _ in 0..amount {
-            snake.move_one(&direction);
-            set.insert(snake.segments.last().unwrap().clone());
-        }
-    }
-    set.len().to_string()
-}
\ No newline at end of file
+    num_tail_positions(path, 10).to_string()
+}
+
+#[test]
+fn test_follow_rules() {
+    let motions = |motions: &[(Direction, usize)]| motions.iter()
+        .map(|(direction, amount)| Motion { direction: *direction, amount: *amount })
+        .collect::<Vec<Motion>>();
+    let tails = |rule: FollowRule, motions: &[Motion]| Snake::with_rule(2, rule)
+        .unwrap()
+        .simulate(motions.iter().copied())
+        .map(|knots| knots[1])
+        .collect::<Vec<Point>>();
+
+    // the standard rule would cut the corner to (1, 1)
+    let up_then_right = motions(&[(Direction::Up, 1), (Direction::Right, 2)]);
+    assert_eq!(tails(FollowRule::STANDARD, &up_then_right), [(0, 0), (0, 0), (1, 1)]);
+    assert_eq!(tails(FollowRule::Orthogonal, &up_then_right), [(0, 0), (0, 0), (1, 0)]);
+
+    // stretches to 2 apart, then snaps back to touching in one go
+    let right = motions(&[(Direction::Right, 4)]);
+    assert_eq!(tails(FollowRule::Elastic(2), &right), [(0, 0), (0, 0), (2, 0), (2, 0)]);
+
+    assert!(Snake::with_rule(1, FollowRule::STANDARD).is_err());
+}
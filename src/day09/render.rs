@@ -0,0 +1,236 @@
+use std::{collections::HashSet, path::Path, str::FromStr};
+
+use crate::utils::{AOCError, AOCResult, parse_i32, parse_usize};
+
+use super::{Point, Motion, Snake, FollowRule, read_motions};
+
+/// What to draw while replaying the motions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderMode {
+    /// a frame after every single step, like the part 1 worked example
+    EveryStep,
+    /// a frame at the end of each motion, like the part 2 worked example
+    EveryMotion,
+    /// just the cells the tail visited
+    FinalVisited,
+}
+
+impl FromStr for RenderMode {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "steps" => Ok(RenderMode::EveryStep),
+            "motions" => Ok(RenderMode::EveryMotion),
+            "visited" => Ok(RenderMode::FinalVisited),
+            _ => Err(AOCError::new(format!("unknown render mode {}, expected steps, motions or visited", s))),
+        }
+    }
+}
+
+/// The rectangle of the board that gets drawn. `(min_x, min_y)` is the bottom left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Viewport {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    /// the smallest viewport that fits every point, and the start
+    fn fitting<'a>(points: impl Iterator<Item = &'a Point>) -> Self {
+        let (min_x, min_y, max_x, max_y) = points.fold((0, 0, 0, 0), |(min_x, min_y, max_x, max_y), point| {
+            (min_x.min(point.0), min_y.min(point.1), max_x.max(point.0), max_y.max(point.1))
+        });
+        Self { min_x, min_y, width: (max_x - min_x + 1) as usize, height: (max_y - min_y + 1) as usize }
+    }
+
+    /// points of the viewport, top row first
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Point>> {
+        let (min_x, width) = (self.min_x, self.width as i32);
+        (self.min_y..self.min_y + self.height as i32)
+            .rev()
+            .map(move |y| (min_x..min_x + width).map(move |x| (x, y)))
+    }
+}
+
+impl FromStr for Viewport {
+    type Err = AOCError;
+
+    /// parses `min_x,min_y,width,height`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(',').collect();
+        let bad_viewport = || AOCError::new(format!("bad viewport {}, expected min_x,min_y,width,height", s));
+        match parts.as_slice() {
+            [min_x, min_y, width, height] => Ok(Self {
+                min_x: parse_i32(min_x).ok_or_else(bad_viewport)?,
+                min_y: parse_i32(min_y).ok_or_else(bad_viewport)?,
+                width: parse_usize(width).ok_or_else(bad_viewport)?,
+                height: parse_usize(height).ok_or_else(bad_viewport)?,
+            }),
+            _ => Err(bad_viewport()),
+        }
+    }
+}
+
+fn knot_label(index: usize, num_knots: usize) -> char {
+    match (index, num_knots) {
+        (0, _) => 'H',
+        (1, 2) => 'T',
+        (i, _) if i < 10 => char::from_digit(i as u32, 10).expect("checked it's a digit"),
+        _ => '*',
+    }
+}
+
+/// one frame in the puzzle's format. where knots overlap the one nearest the head is drawn
+fn render_frame(knots: &[Point], viewport: &Viewport) -> String {
+    let mut out = String::new();
+    for row in viewport.rows() {
+        for point in row {
+            let c = match knots.iter().position(|knot| *knot == point) {
+                Some(index) => knot_label(index, knots.len()),
+                None if point == (0, 0) => 's',
+                None => '.',
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_visited(visited: &HashSet<Point>, viewport: &Viewport) -> String {
+    let mut out = String::new();
+    for row in viewport.rows() {
+        for point in row {
+            let c = match (point == (0, 0), visited.contains(&point)) {
+                (true, _) => 's',
+                (false, true) => '#',
+                (false, false) => '.',
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn motion_header(motion: &Motion) -> String {
+    format!("== {} {} ==\n\n", motion.direction.name(), motion.amount)
+}
+
+/// Replays the motions in `path` with a rope of `num_knots` knots following `rule` and draws
+/// it. Without a viewport the drawing is sized to fit everywhere the rope goes.
+pub fn render_simulation<P: AsRef<Path>>(
+    path: P,
+    num_knots: usize,
+    rule: FollowRule,
+    mode: RenderMode,
+    viewport: Option<Viewport>,
+) -> AOCResult<String> {
+    let motions: Vec<Motion> = read_motions(path).collect();
+    let start = vec![(0, 0); num_knots];
+    let states: Vec<Vec<Point>> = Snake::with_rule(num_knots, rule)?.simulate(motions.iter().copied()).collect();
+    let viewport = viewport.unwrap_or_else(|| Viewport::fitting(states.iter().flatten()));
+
+    let mut out = String::new();
+    match mode {
+        RenderMode::FinalVisited => {
+            let visited = states.iter().map(|knots| knots[num_knots - 1]).collect();
+            out.push_str(&render_visited(&visited, &viewport));
+        },
+        RenderMode::EveryStep | RenderMode::EveryMotion => {
+            out.push_str("== Initial State ==\n\n");
+            out.push_str(&render_frame(&start, &viewport));
+            let mut states = states.iter();
+            for motion in motions.iter() {
+                out.push('\n');
+                out.push_str(&motion_header(motion));
+                let motion_states: Vec<&Vec<Point>> = states.by_ref().take(motion.amount).collect();
+                let frames = match mode {
+                    RenderMode::EveryStep => motion_states,
+                    _ => motion_states.last().copied().into_iter().collect(),
+                };
+                let frames: Vec<String> = frames.into_iter()
+                    .map(|knots| render_frame(knots, &viewport))
+                    .collect();
+                out.push_str(&frames.join("\n"));
+            }
+        },
+    }
+    Ok(out)
+}
+
+#[test]
+fn test_render_sample() {
+    let viewport = Some(Viewport { min_x: 0, min_y: 0, width: 6, height: 5 });
+    let steps = render_simulation("res/day09/input.sample", 2, FollowRule::STANDARD, RenderMode::EveryStep, viewport).unwrap();
+    // the first two motions of the part 1 worked example
+    let expected = "\
+== Initial State ==
+
+......
+......
+......
+......
+H.....
+
+== R 4 ==
+
+......
+......
+......
+......
+TH....
+
+......
+......
+......
+......
+sTH...
+
+......
+......
+......
+......
+s.TH..
+
+......
+......
+......
+......
+s..TH.
+
+== U 4 ==
+
+......
+......
+......
+....H.
+s..T..
+
+......
+......
+....H.
+....T.
+s.....
+
+......
+....H.
+....T.
+......
+s.....
+
+....H.
+....T.
+......
+......
+s.....
+
+";
+    assert!(steps.starts_with(expected), "{}", steps);
+
+    let visited = render_simulation("res/day09/input.sample", 2, FollowRule::STANDARD, RenderMode::FinalVisited, viewport).unwrap();
+    assert_eq!(visited, "..##..\n...##.\n.####.\n....#.\ns###..\n");
+}
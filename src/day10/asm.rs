@@ -0,0 +1,125 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::utils::parse_i32;
+
+use super::instruction::{Arg, Instruction, InstructionSpec, OperandKind, Operand, Register, INSTRUCTION_TABLE};
+
+/// something wrong with an assembly program, along with the (1 indexed) line it's on
+#[derive(Debug, PartialEq, Eq)]
+pub struct AssemblyError {
+    pub line_number: usize,
+    pub message: String,
+}
+
+impl Display for AssemblyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line_number, self.message)
+    }
+}
+
+/// a line with the comment and surrounding whitespace taken off
+fn strip_line(line: &str) -> &str {
+    line.split(['#', ';']).next().unwrap_or("").trim()
+}
+
+fn parse_arg(kind: OperandKind, token: &str, labels: &HashMap<String, usize>) -> Result<Arg, String> {
+    let number = || parse_i32(token).ok_or_else(|| format!("expected a number, got '{}'", token));
+    let register = || Register::parse(token).ok_or_else(|| format!("expected a register, got '{}'", token));
+    match kind {
+        OperandKind::Number => number().map(Arg::Number),
+        OperandKind::Register => register().map(Arg::Register),
+        OperandKind::Value => number().map(Operand::Immediate)
+            .or_else(|_| register().map(Operand::Register))
+            .map(Arg::Value)
+            .map_err(|_| format!("expected a number or a register, got '{}'", token)),
+        OperandKind::Label => labels.get(token)
+            .map(|target| Arg::Target(*target))
+            .ok_or_else(|| format!("no such label '{}'", token)),
+    }
+}
+
+fn parse_instruction(line: &str, labels: &HashMap<String, usize>) -> Result<Instruction, String> {
+    let mut tokens = line.split_whitespace();
+    let name = tokens.next().expect("blank lines are skipped");
+    let spec: &InstructionSpec = INSTRUCTION_TABLE.iter()
+        .find(|spec| spec.name == name)
+        .ok_or_else(|| format!("unknown instruction '{}'", name))?;
+    let tokens: Vec<&str> = tokens.collect();
+    if tokens.len() != spec.operands.len() {
+        return Err(format!("{} takes {} operand(s), got {}", name, spec.operands.len(), tokens.len()));
+    }
+    let args = spec.operands.iter()
+        .zip(tokens)
+        .map(|(kind, token)| parse_arg(*kind, token, labels))
+        .collect::<Result<Vec<Arg>, String>>()?;
+    Ok((spec.build)(&args))
+}
+
+/// Assembles a program. Lines are an instruction, a `label:`, blank, or a comment
+/// starting with `#` or `;`. Every error in the program is reported, not just the first.
+pub fn assemble(lines: impl Iterator<Item = String>) -> Result<Vec<Instruction>, Vec<AssemblyError>> {
+    let lines: Vec<String> = lines.collect();
+    let mut errors = Vec::new();
+
+    // first pass finds where every label points
+    let mut labels: HashMap<String, usize> = HashMap::new();
+    let mut num_instructions = 0;
+    for (ix, line) in lines.iter().enumerate() {
+        let line = strip_line(line);
+        match line.strip_suffix(':') {
+            Some(label) if labels.contains_key(label) => errors.push(AssemblyError {
+                line_number: ix + 1,
+                message: format!("label '{}' is defined twice", label),
+            }),
+            Some(label) => {
+                labels.insert(label.to_string(), num_instructions);
+            },
+            None if line.is_empty() => {},
+            None => num_instructions += 1,
+        }
+    }
+
+    // second pass builds the instructions
+    let mut program = Vec::with_capacity(num_instructions);
+    for (ix, line) in lines.iter().enumerate() {
+        let line = strip_line(line);
+        if line.is_empty() || line.ends_with(':') {
+            continue;
+        }
+        match parse_instruction(line, &labels) {
+            Ok(instruction) => program.push(instruction),
+            Err(message) => errors.push(AssemblyError { line_number: ix + 1, message }),
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(program),
+        false => {
+            errors.sort_by_key(|err| err.line_number);
+            Err(errors)
+        },
+    }
+}
+
+#[test]
+fn test_assemble() {
+    let source = |text: &str| text.lines().map(|line| line.to_string()).collect::<Vec<_>>().into_iter();
+    let program = assemble(source("set y 3\nloop:  # count down\n  mulx 2\n  add y -1\n  jnz y loop\n")).unwrap();
+    assert_eq!(program, vec![
+        Instruction::Set(Register::Y, Operand::Immediate(3)),
+        Instruction::MulX(2),
+        Instruction::Add(Register::Y, Operand::Immediate(-1)),
+        Instruction::JumpIfNotZero(Register::Y, 1),
+    ]);
+    let errors = assemble(source("addx\nfoo 1\njmp nowhere\nset q 1\nadd x y")).unwrap_err();
+    let lines: Vec<usize> = errors.iter().map(|err| err.line_number).collect();
+    assert_eq!(lines, vec![1, 2, 3, 4]);
+
+    // doubling forever wraps around instead of overflowing
+    let program = assemble(source("set x 1\nloop:\n  mulx 2\n  jmp loop\n")).unwrap();
+    let mut cpu = super::Cpu::new(program);
+    for _ in 0..1 + 3 * 40 {
+        cpu.tick();
+    }
+    assert_eq!(cpu.registers().x(), 0);
+}
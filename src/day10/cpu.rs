@@ -0,0 +1,171 @@
+use std::fmt::{Display, Write};
+
+use crate::utils::{AOCError, AOCResult};
+
+use super::{Instruction, Register};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers {
+    values: [i32; Register::ALL.len()],
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> i32 {
+        self.values[register.index()]
+    }
+
+    pub fn set(&mut self, register: Register, value: i32) {
+        self.values[register.index()] = value;
+    }
+
+    /// the register the crt reads the sprite position from
+    pub fn x(&self) -> i32 {
+        self.get(Register::X)
+    }
+}
+
+impl Default for Registers {
+    /// x starts at 1, everything else at 0
+    fn default() -> Self {
+        let mut registers = Self { values: [0; Register::ALL.len()] };
+        registers.set(Register::X, 1);
+        registers
+    }
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values: Vec<String> = self.values.iter().map(|value| value.to_string()).collect();
+        f.write_str(&values.join(" "))
+    }
+}
+
+/// Everything that happened in one clock cycle.
+#[derive(Clone, Copy, Debug)]
+pub struct Tick {
+    /// cycles are numbered from 1, like in the puzzle
+    pub cycle: usize,
+    /// index of the instruction that was running
+    pub pc: usize,
+    pub instruction: Instruction,
+    /// register values while the cycle was running
+    pub during: Registers,
+    /// register values once the cycle finished
+    pub after: Registers,
+}
+
+pub struct Cpu {
+    program: Vec<Instruction>,
+    registers: Registers,
+    pc: usize,
+    cycle: usize,
+    /// how many cycles the current instruction has been running for
+    cycles_into_instruction: usize,
+}
+
+impl Cpu {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Self { program, registers: Registers::default(), pc: 0, cycle: 0, cycles_into_instruction: 0 }
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    /// the cycle that will run next, numbered from 1
+    pub fn next_cycle(&self) -> usize {
+        self.cycle + 1
+    }
+
+    /// the instruction that will run next, and where it is in the program
+    pub fn current_instruction(&self) -> Option<(usize, Instruction)> {
+        self.program.get(self.pc).map(|instruction| (self.pc, *instruction))
+    }
+
+    /// true if the last cycle finished an instruction, or nothing has run yet
+    pub fn at_instruction_boundary(&self) -> bool {
+        self.cycles_into_instruction == 0
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Runs one clock cycle. Instructions only touch the registers on their last cycle.
+    pub fn tick(&mut self) -> Option<Tick> {
+        let instruction = *self.program.get(self.pc)?;
+        let during = self.registers;
+        self.cycle += 1;
+        self.cycles_into_instruction += 1;
+        let pc = self.pc;
+        if self.cycles_into_instruction == instruction.cycles() {
+            self.pc = match instruction.execute(&mut self.registers) {
+                Some(target) => target,
+                None => self.pc + 1,
+            };
+            self.cycles_into_instruction = 0;
+        }
+        Some(Tick { cycle: self.cycle, pc, instruction, during, after: self.registers })
+    }
+
+    /// Runs the rest of the current instruction, returning every cycle it took.
+    pub fn step(&mut self) -> Vec<Tick> {
+        let mut ticks = Vec::new();
+        while let Some(tick) = self.tick() {
+            ticks.push(tick);
+            if self.at_instruction_boundary() {
+                break;
+            }
+        }
+        ticks
+    }
+
+    /// Every cycle until the program finishes. Programs with jumps can loop forever, so
+    /// it's an error if the program is still running after `max_cycles` cycles.
+    pub fn run(mut self, max_cycles: usize) -> AOCResult<Vec<Tick>> {
+        let ticks: Vec<Tick> = self.by_ref().take(max_cycles).collect();
+        match self.is_halted() {
+            true => Ok(ticks),
+            false => Err(AOCError::new(format!("program still running after {} cycles", max_cycles))),
+        }
+    }
+
+    /// a table of every cycle until the program finishes, with the same limit as `run`
+    pub fn trace(mut self, max_cycles: usize) -> AOCResult<String> {
+        let mut out = String::new();
+        let names: Vec<&str> = Register::ALL.iter().map(|register| register.name()).collect();
+        writeln!(out, "cycle\tpc\tinstruction\tduring {}\tafter {}", names.join(" "), names.join(" ")).unwrap();
+        while !self.is_halted() {
+            if self.cycle >= max_cycles {
+                return Err(AOCError::new(format!("program still running after {} cycles", max_cycles)));
+            }
+            for tick in self.step() {
+                writeln!(
+                    out, "{}\t{}\t{}\t{}\t{}",
+                    tick.cycle, tick.pc, tick.instruction, tick.during, tick.after,
+                ).unwrap();
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl Iterator for Cpu {
+    type Item = Tick;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tick()
+    }
+}
+
+#[test]
+fn test_cycle_limit() {
+    let source = |text: &str| text.lines().map(|line| line.to_string()).collect::<Vec<_>>().into_iter();
+    let looping = super::assemble(source("loop:\n  jmp loop\n")).unwrap();
+    assert!(Cpu::new(looping.clone()).run(1000).is_err());
+    assert!(Cpu::new(looping).trace(1000).is_err());
+
+    let halting = super::assemble(source("noop\naddx 3\naddx -5\n")).unwrap();
+    assert_eq!(Cpu::new(halting.clone()).run(5).unwrap().len(), 5);
+    assert!(Cpu::new(halting).run(4).is_err());
+}
@@ -0,0 +1,146 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::utils::AOCError;
+
+use super::Registers;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `#` and `.`, like the puzzle
+    Text,
+    /// coloured blocks for the terminal
+    Ansi,
+    /// a plain PBM image, with frames stacked top to bottom
+    Pbm,
+}
+
+impl FromStr for OutputFormat {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "ansi" => Ok(OutputFormat::Ansi),
+            "pbm" => Ok(OutputFormat::Pbm),
+            _ => Err(AOCError::new(format!("unknown output format {}, expected text, ansi or pbm", s))),
+        }
+    }
+}
+
+/// A screen that draws one pixel per cycle, left to right and top to bottom, and starts a
+/// new frame once the screen is full.
+pub struct Crt {
+    width: usize,
+    height: usize,
+    sprite_width: usize,
+    /// every pixel drawn so far, across all frames
+    pixels: Vec<bool>,
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize, sprite_width: usize) -> Self {
+        assert!(width > 0 && height > 0, "crt needs at least one pixel");
+        Self { width, height, sprite_width, pixels: Vec::new() }
+    }
+
+    /// the 40x6 screen with a 3 pixel sprite from the puzzle
+    pub fn standard() -> Self {
+        Self::new(40, 6, 3)
+    }
+
+    /// true if the sprite centred on `sprite_x` covers `column`. even width sprites
+    /// stick out one more pixel to the right
+    fn sprite_covers(&self, sprite_x: i32, column: usize) -> bool {
+        let left = sprite_x - (self.sprite_width as i32 - 1) / 2;
+        let right = left + self.sprite_width as i32 - 1;
+        (left..=right).contains(&(column as i32))
+    }
+
+    /// Draws the pixel for one cycle, using the registers as they were during that cycle.
+    pub fn on_cycle(&mut self, during: &Registers) {
+        let column = self.pixels.len() % self.width;
+        self.pixels.push(self.sprite_covers(during.x(), column));
+    }
+
+    /// how many cycles it takes to draw a whole frame
+    pub fn frame_size(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// the row being drawn, or the last one finished if the next cycle starts a new row
+    pub fn current_row(&self) -> (usize, &[bool]) {
+        let Some(last) = self.pixels.len().checked_sub(1) else { return (0, &[]) };
+        let row = last / self.width;
+        (row % self.height, &self.pixels[row * self.width..])
+    }
+
+    /// where the sprite is for a given x, as a row of pixels
+    pub fn sprite_row(&self, sprite_x: i32) -> Vec<bool> {
+        (0..self.width).map(|column| self.sprite_covers(sprite_x, column)).collect()
+    }
+
+    /// every frame drawn so far; the last one may be partly drawn, the rest is left dark
+    pub fn frames(&self) -> Vec<Vec<bool>> {
+        self.pixels
+            .chunks(self.frame_size())
+            .map(|frame| {
+                let mut frame = frame.to_vec();
+                frame.resize(self.frame_size(), false);
+                frame
+            })
+            .collect()
+    }
+
+    fn render_rows(&self, frame: &[bool], lit: &str, dark: &str) -> String {
+        frame.chunks(self.width)
+            .map(|row| row.iter().map(|pixel| match pixel { true => lit, false => dark }).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn render(&self, format: OutputFormat) -> String {
+        let frames = self.frames();
+        match format {
+            OutputFormat::Text => frames.iter()
+                .map(|frame| self.render_rows(frame, "#", "."))
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+            OutputFormat::Ansi => frames.iter()
+                .map(|frame| self.render_rows(frame, "\x1b[97;107m##\x1b[0m", "\x1b[90;40m..\x1b[0m"))
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+            OutputFormat::Pbm if frames.is_empty() => "P1\n0 0\n".to_string(),
+            OutputFormat::Pbm => {
+                // frames are separated by a blank row
+                let height = frames.len() * (self.height + 1) - 1;
+                let mut out = format!("P1\n{} {}\n", self.width, height);
+                for (i, frame) in frames.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(out, "{}", vec!["0"; self.width].join(" ")).unwrap();
+                    }
+                    for row in frame.chunks(self.width) {
+                        let row: Vec<&str> = row.iter().map(|pixel| match pixel { true => "1", false => "0" }).collect();
+                        writeln!(out, "{}", row.join(" ")).unwrap();
+                    }
+                }
+                out
+            },
+        }
+    }
+}
+
+#[test]
+fn test_crt_frames() {
+    let crt = Crt::new(4, 2, 3);
+    assert_eq!(crt.render(OutputFormat::Pbm), "P1\n0 0\n");
+    assert_eq!(crt.render(OutputFormat::Text), "");
+
+    // x stays at 1, so the first three columns light up on every row, and the last two
+    // cycles start a second frame that's left dark past what was drawn
+    let mut crt = Crt::new(4, 2, 3);
+    for _ in 0..10 {
+        crt.on_cycle(&Registers::default());
+    }
+    assert_eq!(crt.render(OutputFormat::Text), "###.\n###.\n\n##..\n....");
+    assert_eq!(crt.render(OutputFormat::Pbm).lines().nth(1), Some("4 5"));
+}
@@ -0,0 +1,330 @@
+use std::{io::{self, BufRead, Write}, str::FromStr};
+
+use crate::utils::{AOCError, parse_i32, parse_usize};
+
+use super::{Cpu, Crt, Register, Registers};
+use super::cpu::Tick;
+
+const HELP: &str = "\
+commands:
+  tick [n]               run n cycles (default 1)
+  step [n]               run n whole instructions (default 1)
+  continue               run until a breakpoint or the program ends
+  break cycle <n>        stop right before cycle n, so `regs` shows the values during it
+  break <reg> <op> <n>   stop when the condition becomes true, op is one of == != < <= > >=
+  breaks                 list breakpoints
+  delete <n>             remove breakpoint n
+  watch <reg>            print whenever the register changes
+  unwatch <reg>          stop watching a register
+  regs                   show the registers
+  crt                    show the crt row being drawn
+  help                   show this
+  quit                   stop debugging";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Comparison {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "==" => Some(Comparison::Equal),
+            "!=" => Some(Comparison::NotEqual),
+            "<" => Some(Comparison::Less),
+            "<=" => Some(Comparison::LessEqual),
+            ">" => Some(Comparison::Greater),
+            ">=" => Some(Comparison::GreaterEqual),
+            _ => None,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterEqual => ">=",
+        }
+    }
+
+    fn holds(&self, lhs: i32, rhs: i32) -> bool {
+        match self {
+            Comparison::Equal => lhs == rhs,
+            Comparison::NotEqual => lhs != rhs,
+            Comparison::Less => lhs < rhs,
+            Comparison::LessEqual => lhs <= rhs,
+            Comparison::Greater => lhs > rhs,
+            Comparison::GreaterEqual => lhs >= rhs,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Breakpoint {
+    /// stop before this cycle runs
+    Cycle(usize),
+    /// stop after a cycle that makes the condition go from false to true
+    Condition(Register, Comparison, i32),
+}
+
+impl Breakpoint {
+    fn describe(&self) -> String {
+        match self {
+            Breakpoint::Cycle(cycle) => format!("cycle {}", cycle),
+            Breakpoint::Condition(register, comparison, value) => {
+                format!("{} {} {}", register.name(), comparison.symbol(), value)
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Command {
+    Tick(usize),
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    ListBreakpoints,
+    Delete(usize),
+    Watch(Register),
+    Unwatch(Register),
+    Registers,
+    Crt,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split_whitespace().collect();
+        let number = |s: &str| parse_usize(s).ok_or_else(|| AOCError::new(format!("expected a number, got '{}'", s)));
+        let count = |s: &str| match number(s)? {
+            0 => Err(AOCError::new("expected at least 1")),
+            n => Ok(n),
+        };
+        let register = |s: &str| Register::parse(s).ok_or_else(|| AOCError::new(format!("no such register '{}'", s)));
+        match parts.as_slice() {
+            ["tick" | "t"] => Ok(Command::Tick(1)),
+            ["tick" | "t", n] => Ok(Command::Tick(count(n)?)),
+            ["step" | "s"] => Ok(Command::Step(1)),
+            ["step" | "s", n] => Ok(Command::Step(count(n)?)),
+            ["continue" | "c"] => Ok(Command::Continue),
+            ["break" | "b", "cycle", n] => Ok(Command::Break(Breakpoint::Cycle(number(n)?))),
+            ["break" | "b", reg, op, value] => {
+                let comparison = Comparison::parse(op)
+                    .ok_or_else(|| AOCError::new(format!("unknown comparison '{}'", op)))?;
+                let value = parse_i32(value)
+                    .ok_or_else(|| AOCError::new(format!("expected a number, got '{}'", value)))?;
+                Ok(Command::Break(Breakpoint::Condition(register(reg)?, comparison, value)))
+            },
+            ["breaks"] => Ok(Command::ListBreakpoints),
+            ["delete" | "d", n] => Ok(Command::Delete(number(n)?)),
+            ["watch" | "w", reg] => Ok(Command::Watch(register(reg)?)),
+            ["unwatch", reg] => Ok(Command::Unwatch(register(reg)?)),
+            ["regs" | "r"] => Ok(Command::Registers),
+            ["crt"] => Ok(Command::Crt),
+            ["help" | "h"] => Ok(Command::Help),
+            ["quit" | "q"] => Ok(Command::Quit),
+            _ => Err(AOCError::new(format!("unknown command '{}', try help", s))),
+        }
+    }
+}
+
+/// Why running stopped.
+enum Stop {
+    Breakpoint(usize),
+    Halted,
+    /// ran as many ticks or steps as were asked for
+    Done,
+}
+
+pub struct Debugger {
+    cpu: Cpu,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Register>,
+    crt: Crt,
+    /// the cycle a cycle breakpoint last stopped in front of, so resuming doesn't stop there again
+    stopped_before: Option<usize>,
+}
+
+impl Debugger {
+    pub fn new(cpu: Cpu) -> Self {
+        Self { cpu, breakpoints: Vec::new(), watches: Vec::new(), crt: Crt::standard(), stopped_before: None }
+    }
+
+    /// Reads commands from `input` until it runs out or says quit.
+    pub fn run(&mut self, input: impl BufRead, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", self.status())?;
+        write!(out, "(dbg) ")?;
+        out.flush()?;
+        for line in input.lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                match line.parse::<Command>() {
+                    Ok(Command::Quit) => return Ok(()),
+                    Ok(command) => self.execute(command, out)?,
+                    Err(err) => writeln!(out, "{}", err)?,
+                }
+            }
+            write!(out, "(dbg) ")?;
+            out.flush()?;
+        }
+        writeln!(out)
+    }
+
+    fn execute(&mut self, command: Command, out: &mut impl Write) -> io::Result<()> {
+        match command {
+            Command::Tick(n) => self.run_until(out, |ticks_run, _| ticks_run == n)?,
+            Command::Step(n) => self.run_until(out, |_, steps_run| steps_run == n)?,
+            Command::Continue => self.run_until(out, |_, _| false)?,
+            Command::Break(breakpoint) => {
+                self.breakpoints.push(breakpoint);
+                writeln!(out, "breakpoint {}: {}", self.breakpoints.len() - 1, breakpoint.describe())?;
+            },
+            Command::ListBreakpoints => {
+                for (i, breakpoint) in self.breakpoints.iter().enumerate() {
+                    writeln!(out, "{}: {}", i, breakpoint.describe())?;
+                }
+            },
+            Command::Delete(i) if i < self.breakpoints.len() => {
+                let breakpoint = self.breakpoints.remove(i);
+                writeln!(out, "deleted {}", breakpoint.describe())?;
+            },
+            Command::Delete(i) => writeln!(out, "no breakpoint {}", i)?,
+            Command::Watch(register) => {
+                if !self.watches.contains(&register) {
+                    self.watches.push(register);
+                }
+            },
+            Command::Unwatch(register) => self.watches.retain(|watched| *watched != register),
+            Command::Registers => writeln!(out, "{}", self.status())?,
+            Command::Crt => write!(out, "{}", self.crt_view())?,
+            Command::Help => writeln!(out, "{}", HELP)?,
+            Command::Quit => {},
+        };
+        Ok(())
+    }
+
+    /// Ticks until a breakpoint fires, the program ends, or `done(ticks_run, steps_run)` says so.
+    fn run_until(&mut self, out: &mut impl Write, done: impl Fn(usize, usize) -> bool) -> io::Result<()> {
+        let mut ticks_run = 0;
+        let mut steps_run = 0;
+        let resumed_before = self.stopped_before.take();
+        let stop = loop {
+            let next_cycle = self.cpu.next_cycle();
+            if resumed_before != Some(next_cycle) && !self.cpu.is_halted() {
+                if let Some(i) = self.cycle_breakpoint(next_cycle) {
+                    self.stopped_before = Some(next_cycle);
+                    break Stop::Breakpoint(i);
+                }
+            }
+            let tick = match self.cpu.tick() {
+                Some(tick) => tick,
+                None => break Stop::Halted,
+            };
+            ticks_run += 1;
+            if self.cpu.at_instruction_boundary() {
+                steps_run += 1;
+            }
+            self.crt.on_cycle(&tick.during);
+            self.report_watches(&tick, out)?;
+            if let Some(i) = self.condition_breakpoint(&tick) {
+                break Stop::Breakpoint(i);
+            }
+            if done(ticks_run, steps_run) {
+                break Stop::Done;
+            }
+        };
+        match stop {
+            Stop::Breakpoint(i) => writeln!(out, "hit breakpoint {}: {}", i, self.breakpoints[i].describe())?,
+            Stop::Halted => writeln!(out, "program finished")?,
+            Stop::Done => {},
+        };
+        writeln!(out, "{}", self.status())
+    }
+
+    /// a breakpoint on the cycle that's about to run
+    fn cycle_breakpoint(&self, next_cycle: usize) -> Option<usize> {
+        self.breakpoints.iter().position(|breakpoint| *breakpoint == Breakpoint::Cycle(next_cycle))
+    }
+
+    /// a condition that the cycle that just ran made true
+    fn condition_breakpoint(&self, tick: &Tick) -> Option<usize> {
+        self.breakpoints.iter().position(|breakpoint| match breakpoint {
+            Breakpoint::Cycle(_) => false,
+            Breakpoint::Condition(register, comparison, value) => {
+                let before = comparison.holds(tick.during.get(*register), *value);
+                let after = comparison.holds(tick.after.get(*register), *value);
+                !before && after
+            },
+        })
+    }
+
+    fn report_watches(&self, tick: &Tick, out: &mut impl Write) -> io::Result<()> {
+        for register in self.watches.iter() {
+            let (before, after) = (tick.during.get(*register), tick.after.get(*register));
+            if before != after {
+                writeln!(out, "cycle {}: {} {} -> {}", tick.cycle, register.name(), before, after)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// the row drawn so far, with the sprite where x says it is for the next cycle
+    fn crt_view(&self) -> String {
+        let pixels = |row: &[bool]| row.iter()
+            .map(|lit| match lit { true => '#', false => '.' })
+            .collect::<String>();
+        let (row, drawn) = self.crt.current_row();
+        let sprite = self.crt.sprite_row(self.cpu.registers().x());
+        format!("{:<8}{}\n{:<8}{}\n", format!("row {}", row), pixels(drawn), "sprite", pixels(&sprite))
+    }
+
+    fn status(&self) -> String {
+        let registers: Registers = self.cpu.registers();
+        let registers: Vec<String> = Register::ALL.iter()
+            .map(|register| format!("{}={}", register.name(), registers.get(*register)))
+            .collect();
+        let next = match self.cpu.current_instruction() {
+            Some((pc, instruction)) => format!("next: [{}] {}", pc, instruction),
+            None => "halted".to_string(),
+        };
+        format!("cycle {}  {}  {}", self.cpu.next_cycle(), registers.join(" "), next)
+    }
+}
+
+#[test]
+fn test_debugger_session() {
+    let cpu = super::load("res/day10/input.sample");
+    let mut debugger = Debugger::new(cpu);
+    let mut out = Vec::new();
+    debugger.run("break cycle 20\ncontinue\nregs\nbreak x < 0\ncontinue\nquit\n".as_bytes(), &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    // the puzzle says x is 21 during the 20th cycle
+    assert!(out.contains("hit breakpoint 0: cycle 20\ncycle 20  x=21"));
+    assert!(out.contains("hit breakpoint 1: x < 0"));
+}
+
+#[test]
+fn test_cycle_breakpoints() {
+    let cpu = super::load("res/day10/input.sample");
+    let mut debugger = Debugger::new(cpu);
+    let mut out = Vec::new();
+    debugger.run("tick 0\nbreak cycle 1\nbreak cycle 3\ncontinue\ncontinue\ncontinue\n".as_bytes(), &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out.contains("expected at least 1"));
+    assert!(out.contains("hit breakpoint 0: cycle 1\ncycle 1  x=1"));
+    // addx 15 finishes at the end of cycle 2
+    assert!(out.contains("hit breakpoint 1: cycle 3\ncycle 3  x=16"));
+    assert!(out.contains("program finished"));
+}
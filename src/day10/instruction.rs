@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use super::Registers;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Register {
+    X,
+    Y,
+    Z,
+    W,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::X, Register::Y, Register::Z, Register::W];
+
+    pub fn parse(s: &str) -> Option<Register> {
+        Register::ALL.into_iter().find(|register| register.name() == s)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+            Register::W => "w",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// a value that comes either straight from the program or out of a register
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Immediate(i32),
+    Register(Register),
+}
+
+impl Operand {
+    fn value(&self, registers: &Registers) -> i32 {
+        match self {
+            Operand::Immediate(value) => *value,
+            Operand::Register(register) => registers.get(*register),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Immediate(value) => write!(f, "{}", value),
+            Operand::Register(register) => write!(f, "{}", register.name()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+    MulX(i32),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Set(Register, Operand),
+    /// jumps hold the index of the instruction they go to, labels are gone by now
+    Jump(usize),
+    JumpIfNotZero(Register, usize),
+}
+
+/// What kind of thing an instruction takes as an operand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperandKind {
+    /// a plain number
+    Number,
+    Register,
+    /// a number or a register
+    Value,
+    /// a label to jump to
+    Label,
+}
+
+/// An operand once it's been parsed and any label looked up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arg {
+    Number(i32),
+    Register(Register),
+    Value(Operand),
+    Target(usize),
+}
+
+pub struct InstructionSpec {
+    pub name: &'static str,
+    pub operands: &'static [OperandKind],
+    /// how many cycles the instruction takes to finish
+    pub cycles: usize,
+    /// builds the instruction from args matching `operands`
+    pub build: fn(&[Arg]) -> Instruction,
+}
+
+/// Every instruction the cpu understands. Adding an op means adding a row here and an
+/// arm in `Instruction::execute`.
+pub const INSTRUCTION_TABLE: &[InstructionSpec] = &[
+    InstructionSpec { name: "noop", operands: &[], cycles: 1, build: |_| Instruction::Noop },
+    InstructionSpec { name: "addx", operands: &[OperandKind::Number], cycles: 2, build: |args| match args {
+        [Arg::Number(value)] => Instruction::AddX(*value),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "mulx", operands: &[OperandKind::Number], cycles: 2, build: |args| match args {
+        [Arg::Number(value)] => Instruction::MulX(*value),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "add", operands: &[OperandKind::Register, OperandKind::Value], cycles: 2, build: |args| match args {
+        [Arg::Register(register), Arg::Value(operand)] => Instruction::Add(*register, *operand),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "mul", operands: &[OperandKind::Register, OperandKind::Value], cycles: 2, build: |args| match args {
+        [Arg::Register(register), Arg::Value(operand)] => Instruction::Mul(*register, *operand),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "set", operands: &[OperandKind::Register, OperandKind::Value], cycles: 1, build: |args| match args {
+        [Arg::Register(register), Arg::Value(operand)] => Instruction::Set(*register, *operand),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "jmp", operands: &[OperandKind::Label], cycles: 1, build: |args| match args {
+        [Arg::Target(target)] => Instruction::Jump(*target),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+    InstructionSpec { name: "jnz", operands: &[OperandKind::Register, OperandKind::Label], cycles: 1, build: |args| match args {
+        [Arg::Register(register), Arg::Target(target)] => Instruction::JumpIfNotZero(*register, *target),
+        _ => unreachable!("the assembler checks operand kinds"),
+    }},
+];
+
+impl Instruction {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Instruction::Noop => "noop",
+            Instruction::AddX(_) => "addx",
+            Instruction::MulX(_) => "mulx",
+            Instruction::Add(..) => "add",
+            Instruction::Mul(..) => "mul",
+            Instruction::Set(..) => "set",
+            Instruction::Jump(_) => "jmp",
+            Instruction::JumpIfNotZero(..) => "jnz",
+        }
+    }
+
+    pub fn spec(&self) -> &'static InstructionSpec {
+        INSTRUCTION_TABLE.iter()
+            .find(|spec| spec.name == self.name())
+            .expect("every instruction is in the table")
+    }
+
+    /// how many cycles the instruction takes to finish
+    pub fn cycles(&self) -> usize {
+        self.spec().cycles
+    }
+
+    /// Applies the instruction's effect, which happens at the end of its last cycle.
+    /// Arithmetic wraps around like a real 32 bit register would. Returns where to jump
+    /// to, if anywhere.
+    pub fn execute(&self, registers: &mut Registers) -> Option<usize> {
+        match self {
+            Instruction::Noop => {},
+            Instruction::AddX(value) => registers.set(Register::X, registers.x().wrapping_add(*value)),
+            Instruction::MulX(value) => registers.set(Register::X, registers.x().wrapping_mul(*value)),
+            Instruction::Add(register, operand) => {
+                registers.set(*register, registers.get(*register).wrapping_add(operand.value(registers)))
+            },
+            Instruction::Mul(register, operand) => {
+                registers.set(*register, registers.get(*register).wrapping_mul(operand.value(registers)))
+            },
+            Instruction::Set(register, operand) => registers.set(*register, operand.value(registers)),
+            Instruction::Jump(target) => return Some(*target),
+            Instruction::JumpIfNotZero(register, target) => {
+                if registers.get(*register) != 0 {
+                    return Some(*target);
+                }
+            },
+        };
+        None
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::AddX(value) | Instruction::MulX(value) => write!(f, "{} {}", self.name(), value),
+            Instruction::Add(register, operand)
+            | Instruction::Mul(register, operand)
+            | Instruction::Set(register, operand) => write!(f, "{} {} {}", self.name(), register.name(), operand),
+            Instruction::Jump(target) => write!(f, "jmp @{}", target),
+            Instruction::JumpIfNotZero(register, target) => write!(f, "jnz {} @{}", register.name(), target),
+        }
+    }
+}
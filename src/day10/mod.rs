@@ -1,79 +1,63 @@
 use std::path::Path;
 
-use crate::utils;
+use crate::utils::{self, AOCResult};
 
-enum Instruction {
-    Noop(),
-    AddX(i32),
-}
+mod asm;
+mod cpu;
+mod crt;
+mod debugger;
+mod instruction;
+mod ocr;
 
-impl Instruction {
-    fn parse(line: String) -> Instruction {
-        let mut parts = line.trim().split(" ");
-        let name = parts.next().unwrap();
-        match name {
-            "noop" => Instruction::Noop(),
-            "addx" => {
-                let value = utils::parse_int(parts.next().unwrap()).unwrap();
-                Instruction::AddX(value)
-            },
-            _ => panic!("Cannot understand instruction {}", name),
-        }
-    }
+pub use asm::assemble;
+pub use cpu::{Cpu, Registers};
+pub use crt::{Crt, OutputFormat};
+pub use debugger::Debugger;
+pub use instruction::{Instruction, Register};
 
-    fn factor_out(&self) -> Vec<Instruction> {
-        match self {
-            Instruction::Noop() => vec![Instruction::Noop()],
-            Instruction::AddX(value) => vec![Instruction::Noop(), Instruction::AddX(*value)],
-        }
-    }
+/// How long whole programs get to run. The puzzle inputs finish in a few hundred cycles,
+/// but programs with jumps can loop forever.
+pub const MAX_CYCLES: usize = 1_000_000;
+
+pub fn load<P: AsRef<Path>>(path: P) -> Cpu {
+    let program = assemble(utils::read_input(path)).unwrap_or_else(|errors| {
+        let errors: Vec<String> = errors.iter().map(|err| err.to_string()).collect();
+        panic!("couldn't assemble program:\n{}", errors.join("\n"))
+    });
+    Cpu::new(program)
 }
 
 pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
-    let lines = utils::read_input(path);
-    let (final_x, signal_strength_total) = lines
-        .map(Instruction::parse)
-        .flat_map(|instruction| instruction.factor_out().into_iter())
-        .enumerate()
-        .fold((1, 0), |(x, signal_strength_total), (cycle, instruction)| {
-            let cycle = (cycle as i32) + 1;
-            let signal_strength_total = match (cycle - 20) % 40 {
-                0 => signal_strength_total + x * cycle,
-                _ => signal_strength_total,
-            };
-            let x = match instruction {
-                Instruction::Noop() => x,
-                Instruction::AddX(y) => x + y,
-            };
-            (x, signal_strength_total)
-        });
+    let ticks = match load(path).run(MAX_CYCLES) {
+        Ok(ticks) => ticks,
+        Err(err) => return err.to_string(),
+    };
+    let signal_strength_total: i32 = ticks.iter()
+        .filter(|tick| tick.cycle >= 20 && (tick.cycle - 20) % 40 == 0)
+        .map(|tick| tick.cycle as i32 * tick.during.x())
+        .sum();
     signal_strength_total.to_string()
 }
 
+/// the letters the program draws on the crt
+pub fn read_screen<P: AsRef<Path>>(path: P) -> AOCResult<String> {
+    ocr::recognize(&answer_part_2(path))
+}
+
+/// Runs the program, drawing on `crt` every cycle, until it ends or `max_frames` frames
+/// are drawn. Programs with jumps can loop forever, so there's always a limit. Returns
+/// false if the program was cut off by the limit rather than finishing.
+pub fn draw<P: AsRef<Path>>(path: P, crt: &mut Crt, max_frames: usize) -> bool {
+    let mut cpu = load(path);
+    for tick in cpu.by_ref().take(max_frames * crt.frame_size()) {
+        crt.on_cycle(&tick.during);
+    }
+    cpu.is_halted()
+}
+
 pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
-    let lines = utils::read_input(path);
-    let xs = lines
-        .map(Instruction::parse)
-        .flat_map(|instruction| instruction.factor_out().into_iter())
-        .scan(1, |x, instruction| {
-            let x_during = *x;
-            *x = match instruction {
-                Instruction::Noop() => *x,
-                Instruction::AddX(y) => *x + y,
-            };
-            Some(x_during)
-        });
-    //let xs = vec![1].into_iter().chain(xs);
-    let pixel_being_rendered = (0..6).flat_map(|_| 0..40);
-    let pixels = xs.zip(pixel_being_rendered)
-        .map(|(x, pixel_being_rendered)| {
-            //println!("pixel: {}, x: {}", &pixel_being_rendered, &x);
-            (x - pixel_being_rendered).abs() <= 1
-        })
-        .map(|x| match x { true => "#", false => "."});
-    let rendered_pixels: Vec<&str> = pixels.collect();
-    let lines: Vec<String> = (0..6)
-        .map(|x| rendered_pixels[x*40..((x+1) * 40)].join(""))
-        .collect();
-    return lines.join("\n");
-}
\ No newline at end of file
+    let mut crt = Crt::standard();
+    // the puzzle programs run for exactly one frame
+    draw(path, &mut crt, 1);
+    crt.render(OutputFormat::Text)
+}
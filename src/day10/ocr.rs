@@ -0,0 +1,71 @@
+use crate::utils::{AOCError, AOCResult};
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// glyphs are drawn with an empty column between them
+const GLYPH_SPACING: usize = 1;
+
+/// the 4x6 block letters the crt draws, each row of a glyph one after the other
+const GLYPHS: &[(char, &str)] = &[
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Reads the letters off a crt screen drawn with `#` and `.`, one screen row per line.
+pub fn recognize(screen: &str) -> AOCResult<String> {
+    let rows: Vec<&[u8]> = screen.lines().map(|line| line.trim_end().as_bytes()).collect();
+    if rows.len() != GLYPH_HEIGHT {
+        return Err(AOCError::new(format!("expected {} rows of pixels, got {}", GLYPH_HEIGHT, rows.len())));
+    }
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let num_glyphs = (width + GLYPH_SPACING) / (GLYPH_WIDTH + GLYPH_SPACING);
+    (0..num_glyphs)
+        .map(|i| {
+            let start = i * (GLYPH_WIDTH + GLYPH_SPACING);
+            let glyph: String = rows.iter()
+                .flat_map(|row| (start..start + GLYPH_WIDTH).map(|col| match row.get(col) {
+                    Some(b'#') => '#',
+                    _ => '.',
+                }))
+                .collect();
+            GLYPHS.iter()
+                .find(|(_, pixels)| *pixels == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| {
+                    let drawing: Vec<&str> = (0..GLYPH_HEIGHT)
+                        .map(|row| &glyph[row * GLYPH_WIDTH..(row + 1) * GLYPH_WIDTH])
+                        .collect();
+                    AOCError::new(format!("unknown glyph at letter {}:\n{}", i + 1, drawing.join("\n")))
+                })
+        })
+        .collect()
+}
+
+#[test]
+fn test_recognize() {
+    let screen = "\
+#..#..###
+#..#...#.
+####...#.
+#..#...#.
+#..#...#.
+#..#..###";
+    assert_eq!(recognize(screen).unwrap(), "HI");
+    let smudged = screen.replacen("####", "##.#", 1);
+    assert!(recognize(&smudged).is_err());
+}
@@ -0,0 +1,91 @@
+use std::{collections::HashMap, path::Path};
+
+use crate::utils::{AOCError, AOCResult};
+
+use super::{Monkey, load};
+
+/// An item's state at the start of a round: its worry level and who's holding it.
+type ItemState = (i64, usize);
+
+/// Moves one item through a round. It keeps getting inspected for as long as it's thrown
+/// to monkeys that haven't had their turn yet, and otherwise waits for the next round.
+fn item_round(monkeys: &[Monkey<i64>], (mut item, mut holder): ItemState, inspections: &mut [u64]) -> AOCResult<ItemState> {
+    loop {
+        inspections[holder] += 1;
+        let (next_item, receiver) = monkeys[holder].inspect(&item)?;
+        item = next_item;
+        if receiver <= holder {
+            return Ok((item, receiver));
+        }
+        holder = receiver;
+    }
+}
+
+/// How many times each monkey inspects one item over `rounds` rounds. Worry levels live
+/// modulo the divisors, so the item's state eventually repeats; from then on every lap of
+/// the cycle adds the same inspections, and the rest of the rounds are skipped.
+fn item_inspections(monkeys: &[Monkey<i64>], start: ItemState, rounds: u64) -> AOCResult<Vec<u64>> {
+    let mut seen: HashMap<ItemState, u64> = HashMap::new();
+    // inspections before each round, so `totals[r]` is the count after `r` rounds
+    let mut totals: Vec<Vec<u64>> = vec![vec![0; monkeys.len()]];
+    let mut state = start;
+    for round in 0..rounds {
+        if let Some(&cycle_start) = seen.get(&state) {
+            let period = round - cycle_start;
+            let (laps, leftover) = ((rounds - cycle_start) / period, (rounds - cycle_start) % period);
+            let lap = &totals[round as usize];
+            let before_cycle = &totals[cycle_start as usize];
+            let partial = &totals[(cycle_start + leftover) as usize];
+            return Ok((0..monkeys.len())
+                .map(|i| partial[i] + laps * (lap[i] - before_cycle[i]))
+                .collect());
+        }
+        seen.insert(state, round);
+        let mut inspections = totals.last().expect("starts with a round").clone();
+        state = item_round(monkeys, state, &mut inspections)?;
+        totals.push(inspections);
+    }
+    Ok(totals.pop().expect("starts with a round"))
+}
+
+/// Inspection counts per monkey after `rounds` rounds of the part 2 rules, found by
+/// following every item on its own until it cycles.
+fn extrapolated_inspections<P: AsRef<Path>>(path: P, rounds: u64) -> AOCResult<Vec<u64>> {
+    let monkeys = load::<i64, _>(path, false, true);
+    if monkeys.iter().any(|monkey| monkey.modulo_space.is_none()) {
+        return Err(AOCError::new("items only cycle when every test is a divisibility test and nothing divides"));
+    }
+    let mut inspections = vec![0; monkeys.len()];
+    for (holder, monkey) in monkeys.iter().enumerate() {
+        for item in monkey.items.iter() {
+            let item_inspections = item_inspections(&monkeys, (*item, holder), rounds)?;
+            for (total, count) in inspections.iter_mut().zip(item_inspections) {
+                *total += count;
+            }
+        }
+    }
+    Ok(inspections)
+}
+
+/// monkey business after `rounds` rounds of the part 2 rules, which can be far more than
+/// could be simulated round by round
+pub fn extrapolate<P: AsRef<Path>>(path: P, rounds: u64) -> AOCResult<String> {
+    let mut inspections = extrapolated_inspections(path, rounds)?;
+    inspections.sort();
+    let top_two: u128 = inspections.iter().rev().take(2).map(|count| *count as u128).product();
+    Ok(top_two.to_string())
+}
+
+#[test]
+fn test_extrapolation_matches_simulation() {
+    let path = "res/day11/input.sample";
+    for rounds in [0, 1, 20, 1000, 10000] {
+        let simulated: Vec<u64> = super::inspections::<i64, _>(path, rounds, false, true)
+            .unwrap()
+            .into_iter()
+            .map(|count| count as u64)
+            .collect();
+        assert_eq!(extrapolated_inspections(path, rounds as u64).unwrap(), simulated);
+    }
+    assert_eq!(extrapolate(path, 10000).unwrap(), "2713310158");
+}
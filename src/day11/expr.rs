@@ -0,0 +1,251 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::utils::{AOCError, AOCResult};
+
+use super::Worry;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl BinaryOp {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(BinaryOp::Add),
+            '-' => Some(BinaryOp::Subtract),
+            '*' => Some(BinaryOp::Multiply),
+            '/' => Some(BinaryOp::Divide),
+            '%' => Some(BinaryOp::Remainder),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            BinaryOp::Add => '+',
+            BinaryOp::Subtract => '-',
+            BinaryOp::Multiply => '*',
+            BinaryOp::Divide => '/',
+            BinaryOp::Remainder => '%',
+        }
+    }
+
+    /// higher binds tighter
+    fn precedence(&self) -> u8 {
+        match self {
+            BinaryOp::Add | BinaryOp::Subtract => 1,
+            BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Remainder => 2,
+        }
+    }
+}
+
+/// The right hand side of a monkey's `new = ...` operation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Old,
+    Constant(i64),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Errors if it divides by zero, which the parser only rules out when the divisor
+    /// doesn't depend on `old`.
+    pub fn evaluate<W: Worry>(&self, old: &W) -> AOCResult<W> {
+        match self {
+            Expr::Old => Ok(old.clone()),
+            Expr::Constant(value) => Ok(W::constant(*value)),
+            Expr::Binary(op, lhs, rhs) => {
+                let rhs_value = rhs.evaluate(old)?;
+                if matches!(op, BinaryOp::Divide | BinaryOp::Remainder) && rhs_value.cmp_i64(0) == Ordering::Equal {
+                    return Err(AOCError::new(format!("{} divides by zero when old is {}", self, old)));
+                }
+                Ok(W::apply(*op, &lhs.evaluate(old)?, &rhs_value))
+            },
+        }
+    }
+
+    /// the value of an expression that doesn't use `old`, if it can be worked out in an `i64`
+    fn constant_value(&self) -> Option<i64> {
+        match self {
+            Expr::Old => None,
+            Expr::Constant(value) => Some(*value),
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.constant_value()?, rhs.constant_value()?);
+                match op {
+                    BinaryOp::Add => lhs.checked_add(rhs),
+                    BinaryOp::Subtract => lhs.checked_sub(rhs),
+                    BinaryOp::Multiply => lhs.checked_mul(rhs),
+                    BinaryOp::Divide => lhs.checked_div(rhs),
+                    BinaryOp::Remainder => lhs.checked_rem(rhs),
+                }
+            },
+        }
+    }
+}
+
+impl Expr {
+    /// true if the expression divides or takes a remainder anywhere, which breaks
+    /// keeping worry levels modulo something
+    pub fn divides(&self) -> bool {
+        match self {
+            Expr::Old | Expr::Constant(_) => false,
+            Expr::Binary(BinaryOp::Divide | BinaryOp::Remainder, _, _) => true,
+            Expr::Binary(_, lhs, rhs) => lhs.divides() || rhs.divides(),
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Old => write!(f, "old"),
+            Expr::Constant(value) => write!(f, "{}", value),
+            Expr::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op.symbol(), rhs),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Old,
+    Number(i64),
+    Op(BinaryOp),
+    Open,
+    Close,
+}
+
+/// Splits an expression into tokens, each with the byte offset it starts at.
+fn tokenize(s: &str) -> Result<Vec<(usize, Token)>, AOCError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((pos, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            c if c.is_ascii_digit() => {
+                let mut end = pos + 1;
+                while let Some((next, _)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = next + 1;
+                }
+                let value = s[pos..end].parse()
+                    .map_err(|_| AOCError::new(format!("number too big at {}: {}", pos, &s[pos..end])))?;
+                Token::Number(value)
+            },
+            'o' if s[pos..].starts_with("old") => {
+                chars.nth(1);
+                Token::Old
+            },
+            c => match BinaryOp::parse(c) {
+                Some(op) => Token::Op(op),
+                None => return Err(AOCError::new(format!("unexpected '{}' at {} in '{}'", c, pos, s))),
+            },
+        };
+        tokens.push((pos, token));
+    }
+    Ok(tokens)
+}
+
+/// Precedence climbing parser over the tokens. Operators of equal precedence group to the left.
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token> {
+        self.tokens.get(self.next).map(|(_, token)| *token)
+    }
+
+    /// where the next token starts, or the end of the input
+    fn position(&self) -> usize {
+        self.tokens.get(self.next).map_or(self.source.len(), |(pos, _)| *pos)
+    }
+
+    fn error(&self, expected: &str) -> AOCError {
+        AOCError::new(format!("expected {} at {} in '{}'", expected, self.position(), self.source))
+    }
+
+    fn parse_expr(&mut self, min_precedence: u8) -> Result<Expr, AOCError> {
+        let mut lhs = self.parse_atom()?;
+        while let Some(Token::Op(op)) = self.peek() {
+            if op.precedence() < min_precedence {
+                break;
+            }
+            let op_position = self.position();
+            self.next += 1;
+            let rhs = self.parse_expr(op.precedence() + 1)?;
+            if matches!(op, BinaryOp::Divide | BinaryOp::Remainder) && rhs.constant_value() == Some(0) {
+                return Err(AOCError::new(format!("division by zero at {} in '{}'", op_position, self.source)));
+            }
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_atom(&mut self) -> Result<Expr, AOCError> {
+        let atom = match self.peek() {
+            Some(Token::Old) => Expr::Old,
+            Some(Token::Number(value)) => Expr::Constant(value),
+            Some(Token::Open) => {
+                self.next += 1;
+                let inner = self.parse_expr(0)?;
+                if self.peek() != Some(Token::Close) {
+                    return Err(self.error("')'"));
+                }
+                inner
+            },
+            _ => return Err(self.error("old, a number or '('")),
+        };
+        self.next += 1;
+        Ok(atom)
+    }
+}
+
+impl FromStr for Expr {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { source: s, tokens: tokenize(s)?, next: 0 };
+        let expr = parser.parse_expr(0)?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(_) => Err(parser.error("an operator")),
+        }
+    }
+}
+
+#[test]
+fn test_parse_and_evaluate() {
+    let eval = |s: &str, old: i64| s.parse::<Expr>().unwrap().evaluate(&old).unwrap();
+    // the forms the puzzle uses
+    assert_eq!(eval("old * 19", 79), 1501);
+    assert_eq!(eval("old * old", 79), 6241);
+    assert_eq!(eval("old + 6", 54), 60);
+    assert_eq!(eval("old + old", 3), 6);
+    // precedence, grouping and left associativity
+    assert_eq!(eval("old + 2 * 3", 1), 7);
+    assert_eq!(eval("(old + 2) * 3", 1), 9);
+    assert_eq!(eval("old - 3 - 2", 10), 5);
+    assert_eq!(eval("old / 2 % 3", 10), 2);
+    assert_eq!(eval("((old))", 4), 4);
+    assert_eq!("old + 2 * old".parse::<Expr>().unwrap().to_string(), "(old + (2 * old))");
+
+    assert!("old +".parse::<Expr>().unwrap_err().to_string().contains("at 5"));
+    assert!("(old * 2".parse::<Expr>().unwrap_err().to_string().contains("expected ')' at 8"));
+    assert!("old ^ 2".parse::<Expr>().unwrap_err().to_string().contains("'^' at 4"));
+    assert!("old 2".parse::<Expr>().unwrap_err().to_string().contains("expected an operator at 4"));
+    assert!("old / 0".parse::<Expr>().unwrap_err().to_string().contains("division by zero at 4"));
+    assert!("old % (3 - 3)".parse::<Expr>().unwrap_err().to_string().contains("division by zero at 4"));
+    // a divisor that depends on old can only be caught while evaluating
+    let divides_by_old = "6 / (old - 2)".parse::<Expr>().unwrap();
+    assert_eq!(divides_by_old.evaluate(&5i64).unwrap(), 2);
+    let err = divides_by_old.evaluate(&2i64).unwrap_err();
+    assert!(err.to_string().contains("(6 / (old - 2)) divides by zero when old is 2"));
+}
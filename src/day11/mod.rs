@@ -1,37 +1,55 @@
-use std::{path::Path, collections::VecDeque};
+use std::{path::Path, collections::VecDeque, cmp::Ordering};
 
-use crate::utils::{self, AOCError, parse_i64, parse_usize};
+use crate::utils::{self, AOCError, AOCResult, parse_i64, parse_usize, bigint::BigInt};
 
-type WorryItem = i64;
+mod cycles;
+mod expr;
+mod report;
+mod worry;
 
-#[derive(Debug)]
-enum Op {
-    Multiply(i64),
-    MultiplyOld,
-    Add(i64),
-    AddOld,
+pub use cycles::extrapolate;
+use expr::{BinaryOp, Expr};
+pub use report::{RoundSelection, ReportFormat, report};
+pub use worry::Backend;
+use worry::Worry;
+
+/// Where a monkey throws an item, decided by the item's worry level.
+#[derive(Clone, Copy, Debug)]
+enum Test {
+    DivisibleBy(i64),
+    GreaterThan(i64),
+    LessThan(i64),
 }
 
-impl Op {
-    fn apply(&self, old: WorryItem) -> WorryItem {
-        match self {
-            Op::Multiply(x) => old * x,
-            Op::MultiplyOld => old * old,
-            Op::Add(x) => old + x,
-            Op::AddOld => old + old,
+impl Test {
+    fn parse(line: &str) -> Self {
+        let value = line.split(' ').map(parse_i64).next_back().flatten()
+            .unwrap_or_else(|| panic!("test without a number: '{}'", line.trim()));
+        match line.trim().split(' ').nth(1) {
+            Some("divisible") if value == 0 => panic!("can't test for divisibility by zero: '{}'", line.trim()),
+            Some("divisible") => Test::DivisibleBy(value),
+            Some("greater") => Test::GreaterThan(value),
+            Some("less") => Test::LessThan(value),
+            _ => panic!("unknown test '{}'", line.trim()),
         }
     }
 
-    fn apply_mod(&self, old: WorryItem, modulo: i64) -> WorryItem {
-        self.apply(old) % modulo
+    fn passes<W: Worry>(&self, item: &W) -> bool {
+        match self {
+            Test::DivisibleBy(divisor) => {
+                W::apply(BinaryOp::Remainder, item, &W::constant(*divisor)).cmp_i64(0) == Ordering::Equal
+            },
+            Test::GreaterThan(value) => item.cmp_i64(*value) == Ordering::Greater,
+            Test::LessThan(value) => item.cmp_i64(*value) == Ordering::Less,
+        }
     }
 }
 
 #[derive(Debug)]
-struct Monkey {
-    items: VecDeque<WorryItem>,
-    operation: Op,
-    test_divisible_by: i64,
+struct Monkey<W> {
+    items: VecDeque<W>,
+    operation: Expr,
+    test: Test,
     monkey_on_true: usize,
     monkey_on_false: usize,
     num_examinations: usize,
@@ -39,7 +57,7 @@ struct Monkey {
     do_worry_div_three: bool,
 }
 
-impl Monkey {
+impl<W: Worry> Monkey<W> {
 
     fn parse_monkeys(lines: &mut impl Iterator<Item = String>, do_worry_div_three: bool) -> Vec<Self> {
         let mut monkeys: Vec<Self> = Vec::new();
@@ -58,37 +76,29 @@ impl Monkey {
             Some(_) => Ok(()),
             None => return Err(AOCError::new("out of data")),
         }?;
-        let starting_items: VecDeque<i64> = lines.next()
+        let starting_items: VecDeque<W> = lines.next()
             .unwrap()
             .split("Starting items: ")
             .flat_map(|substr| substr.split(", "))
             .map(parse_i64)
             .filter(|x| x.is_some())
-            .map(|x| x.expect("already filtered out Nones"))
+            .map(|x| W::constant(x.expect("already filtered out Nones")))
             .collect();
         let op_string_line = lines.next().unwrap();
-        let mut op_string_parts = op_string_line
-            .split("new = old ")
+        let expression = op_string_line
+            .split("new = ")
             .last()
-            .expect("must be present")
-            .split(" ");
-        let op = op_string_parts.next().unwrap();
-        let operand = parse_i64(op_string_parts.next().unwrap());
-        let operation = match (op, operand) {
-            ("*", None) => Op::MultiplyOld,
-            ("*", Some(i)) => Op::Multiply(i),
-            ("+", None) => Op::AddOld,
-            ("+", Some(i)) => Op::Add(i),
-            _ => panic!("Unknown operation '{}'", op),
-        };
-        let test_divisible_by = lines.next().unwrap().split(" ").map(parse_i64).last().unwrap().unwrap();
+            .expect("must be present");
+        let operation: Expr = expression.parse()
+            .unwrap_or_else(|err| panic!("bad operation '{}': {}", expression.trim(), err));
+        let test = Test::parse(&lines.next().unwrap());
         let monkey_on_true = lines.next().unwrap().split(" ").map(parse_usize).last().unwrap().unwrap();
         let monkey_on_false = lines.next().unwrap().split(" ").map(parse_usize).last().unwrap().unwrap();
         lines.next(); // consume newline (or not, idc)
         Ok(Self {
             items: starting_items,
             operation,
-            test_divisible_by,
+            test,
             monkey_on_true,
             monkey_on_false,
             num_examinations: 0,
@@ -97,79 +107,156 @@ impl Monkey {
         })
     }
 
-    fn examine_items(&mut self) -> Vec<(WorryItem, usize)> {
+    /// the item's new worry level after this monkey inspects it, and who it's thrown to
+    fn inspect(&self, item: &W) -> AOCResult<(W, usize)> {
+        let item = match self.modulo_space {
+            Some(modulo) => W::apply(BinaryOp::Remainder, &self.operation.evaluate(item)?, &W::constant(modulo)),
+            None => self.operation.evaluate(item)?,
+        };
+        let item = match self.do_worry_div_three {
+            true => W::apply(BinaryOp::Divide, &item, &W::constant(3)),
+            false => item,
+        };
+        let reciever_monkey = match self.test.passes(&item) {
+            true => self.monkey_on_true,
+            false => self.monkey_on_false,
+        };
+        Ok((item, reciever_monkey))
+    }
+
+    fn examine_items(&mut self) -> AOCResult<Vec<(W, usize)>> {
         let mut items_to_send = Vec::new();
         for _ in 0..self.items.len() {
             let item = self.items.pop_front().unwrap();
-            let item = match self.modulo_space {
-                Some(modulo) => self.operation.apply_mod(item, modulo),
-                None => self.operation.apply(item),
-            };
-            let item = match self.do_worry_div_three { true => item / 3, false => item };
-            let reciever_monkey = match item % self.test_divisible_by {
-                0 => self.monkey_on_true,
-                _ => self.monkey_on_false,
-            };
-            items_to_send.push((item, reciever_monkey));
+            items_to_send.push(self.inspect(&item)?);
             self.num_examinations += 1;
         }
-        items_to_send
+        Ok(items_to_send)
     }
 }
 
-fn perform_round(monkeys: &mut Vec<Monkey>) {
+fn perform_round<W: Worry>(monkeys: &mut [Monkey<W>]) -> AOCResult<()> {
     for i in 0..monkeys.len() {
         let item_distribution = monkeys.get_mut(i)
             .expect("index ok")
-            .examine_items();
+            .examine_items()?;
         for (item, reciever) in item_distribution {
             let monkey = monkeys.get_mut(reciever).expect("index ok");
             monkey.items.push_back(item);
         }
     }
+    Ok(())
 }
 
-pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
+/// The product of every divisor, which worry levels can be kept modulo without changing
+/// where any item goes. Only valid when every test is a divisibility test and nothing
+/// divides the worry level, so `None` otherwise.
+fn modulo_space<W>(monkeys: &[Monkey<W>]) -> Option<i64> {
+    monkeys.iter()
+        .map(|monkey| match (monkey.test, monkey.do_worry_div_three) {
+            (Test::DivisibleBy(divisor), false) if !monkey.operation.divides() => Some(divisor),
+            _ => None,
+        })
+        .try_fold(1i64, |product, divisor| product.checked_mul(divisor?))
+}
+
+/// the monkeys in `path`, keeping worry levels modulo the divisors if `reduce` and that's valid
+fn load<W: Worry, P: AsRef<Path>>(path: P, relief: bool, reduce: bool) -> Vec<Monkey<W>> {
     let mut lines = utils::read_input(path);
-    let mut monkeys = Monkey::parse_monkeys(&mut lines, true);
-    for _ in 0..20 {
-        perform_round(&mut monkeys);
+    let mut monkeys: Vec<Monkey<W>> = Monkey::parse_monkeys(&mut lines, relief);
+    if reduce {
+        let modulo_space = modulo_space(&monkeys);
+        for monkey in monkeys.iter_mut() {
+            monkey.modulo_space = modulo_space;
+        }
     }
-    let mut num_examinations: Vec<usize> = monkeys.iter()
+    monkeys
+}
+
+/// how many items each monkey inspected after `rounds` rounds
+fn inspections<W: Worry, P: AsRef<Path>>(path: P, rounds: usize, relief: bool, reduce: bool) -> AOCResult<Vec<usize>> {
+    let mut monkeys: Vec<Monkey<W>> = load(path, relief, reduce);
+    for _ in 0..rounds {
+        perform_round(&mut monkeys)?;
+    }
+    Ok(monkeys.iter()
         .map(|monkey| monkey.num_examinations)
-        .collect();
+        .collect())
+}
+
+fn monkey_business(inspections: &[usize]) -> usize {
+    let mut num_examinations = inspections.to_vec();
     num_examinations.sort();
-    let monkey_business = num_examinations.into_iter()
+    num_examinations.into_iter()
         .rev()
         .take(2)
         .reduce(|a, b| a * b)
-        .unwrap();
-    monkey_business.to_string()
+        .unwrap()
 }
 
-pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
-    let mut lines = utils::read_input(path);
-    let mut monkeys = Monkey::parse_monkeys(&mut lines, false);
-    let modulo_space: i64 = monkeys.iter()
-        .map(|monkey| monkey.test_divisible_by)
-        .reduce(|a, b| a * b)
-        .unwrap();
-    println!("modulo space: {}", modulo_space);
-    // set the modulo space to prevent overflow
-    for i in 0..monkeys.len() {
-        monkeys.get_mut(i).unwrap().modulo_space = Some(modulo_space);
+/// Monkey business after `rounds` rounds, with or without dividing by three after each
+/// inspection. The machine backend keeps worry levels modulo the divisors when it can.
+pub fn simulate<P: AsRef<Path>>(path: P, rounds: usize, relief: bool, backend: Backend) -> AOCResult<String> {
+    let inspections = match backend {
+        Backend::Machine => inspections::<i64, P>(path, rounds, relief, true)?,
+        Backend::Exact => inspections::<BigInt, P>(path, rounds, relief, false)?,
+    };
+    Ok(monkey_business(&inspections).to_string())
+}
+
+/// Runs the part 2 rules with worry levels kept modulo the divisors and with exact
+/// arithmetic, and checks they agree on every monkey's inspection count.
+pub fn verify_modular<P: AsRef<Path>>(path: P, rounds: usize) -> AOCResult<String> {
+    let modular = inspections::<i64, _>(&path, rounds, false, true)?;
+    let exact = inspections::<BigInt, _>(&path, rounds, false, false)?;
+    match modular == exact {
+        true => Ok(format!("{} rounds agree, inspections {:?}", rounds, exact)),
+        false => Err(AOCError::new(format!("after {} rounds modular gives {:?} but exact gives {:?}", rounds, modular, exact))),
     }
-    for _ in 0..10000 {
-        perform_round(&mut monkeys);
+}
+
+pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
+    match inspections::<i64, P>(path, 20, true, false) {
+        Ok(inspections) => monkey_business(&inspections).to_string(),
+        Err(err) => err.to_string(),
     }
-    let mut num_examinations: Vec<usize> = monkeys.iter()
-        .map(|monkey| monkey.num_examinations)
-        .collect();
-    num_examinations.sort();
-    let monkey_business = num_examinations.into_iter()
-        .rev()
-        .take(2)
-        .reduce(|a, b| a * b)
-        .unwrap();
-    monkey_business.to_string()
-}
\ No newline at end of file
+}
+
+pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
+    match inspections::<i64, P>(path, 10000, false, true) {
+        Ok(inspections) => monkey_business(&inspections).to_string(),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[test]
+fn test_exact_arithmetic() {
+    // the puzzle's part 1 numbers, and part 2's after 20 rounds
+    assert_eq!(simulate("res/day11/input.sample", 20, true, Backend::Exact).unwrap(), "10605");
+    assert_eq!(inspections::<BigInt, _>("res/day11/input.sample", 20, false, false).unwrap(), vec![99, 97, 8, 103]);
+    assert!(verify_modular("res/day11/input.sample", 20).is_ok());
+
+    // the modulo trick needs every test to be divisibility, so this one can only run exactly
+    let variant = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = (old - 3) * 2
+  Test: greater than 100
+    If true: throw to monkey 1
+    If false: throw to monkey 0
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old / 2 + 1
+  Test: less than 0
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+    let mut lines = variant.lines().map(String::from);
+    let mut monkeys: Vec<Monkey<BigInt>> = Monkey::parse_monkeys(&mut lines, false);
+    assert_eq!(modulo_space(&monkeys), None);
+    perform_round(&mut monkeys).unwrap();
+    // 79 and 98 become 152 and 190, go to monkey 1, and come back as 77 and 96 with 28
+    assert_eq!(monkeys[0].items, [28, 77, 96].map(BigInt::from));
+    assert_eq!(monkeys.iter().map(|monkey| monkey.num_examinations).collect::<Vec<_>>(), vec![2, 3]);
+}
@@ -0,0 +1,192 @@
+use std::{fmt::Write, path::Path, str::FromStr};
+
+use crate::utils::{AOCError, AOCResult, bigint::BigInt, parse_usize};
+
+use super::{Backend, Monkey, Worry, load, perform_round};
+
+/// What every monkey holds, and how many items it has inspected, after a round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundSnapshot<W> {
+    pub round: usize,
+    pub items: Vec<Vec<W>>,
+    pub inspections: Vec<usize>,
+}
+
+impl<W: Worry> RoundSnapshot<W> {
+    fn take(round: usize, monkeys: &[Monkey<W>]) -> Self {
+        Self {
+            round,
+            items: monkeys.iter().map(|monkey| monkey.items.iter().cloned().collect()).collect(),
+            inspections: monkeys.iter().map(|monkey| monkey.num_examinations).collect(),
+        }
+    }
+}
+
+/// Which rounds to take snapshots after.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RoundSelection {
+    All,
+    /// every round that's a multiple of this
+    Every(usize),
+    Listed(Vec<usize>),
+}
+
+impl RoundSelection {
+    fn contains(&self, round: usize) -> bool {
+        match self {
+            RoundSelection::All => true,
+            RoundSelection::Every(n) => round.is_multiple_of(*n),
+            RoundSelection::Listed(rounds) => rounds.contains(&round),
+        }
+    }
+
+    /// errors if the selection asks for a round outside `1..=rounds`, which would just be missing
+    fn check(&self, rounds: usize) -> AOCResult<()> {
+        let out_of_range = match self {
+            RoundSelection::All => None,
+            RoundSelection::Every(n) => Some(*n).filter(|n| *n > rounds),
+            RoundSelection::Listed(listed) => listed.iter().copied().find(|round| *round == 0 || *round > rounds),
+        };
+        match out_of_range {
+            Some(round) => Err(AOCError::new(format!("round {} isn't in 1..={}", round, rounds))),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for RoundSelection {
+    type Err = AOCError;
+
+    /// parses `all`, `every:N` or a comma separated list like `1,20,1000`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bad_selection = || AOCError::new(format!("bad rounds {}, expected all, every:N or a list like 1,20,1000", s));
+        match s.split_once(':') {
+            None if s == "all" => Ok(RoundSelection::All),
+            Some(("every", n)) => Ok(RoundSelection::Every(parse_usize(n).filter(|n| *n > 0).ok_or_else(bad_selection)?)),
+            None => s.split(',')
+                .map(|round| parse_usize(round).ok_or_else(bad_selection))
+                .collect::<Result<Vec<usize>, AOCError>>()
+                .map(RoundSelection::Listed),
+            _ => Err(bad_selection()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// the item lists and inspection counts the way the puzzle text writes them
+    Puzzle,
+    /// one `round,monkey,inspections,items` row per monkey per round
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "puzzle" => Ok(ReportFormat::Puzzle),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(AOCError::new(format!("unknown report format {}, expected puzzle or csv", s))),
+        }
+    }
+}
+
+/// Runs `rounds` rounds, taking a snapshot after each selected one.
+fn snapshots<W: Worry>(monkeys: &mut [Monkey<W>], rounds: usize, selection: &RoundSelection) -> AOCResult<Vec<RoundSnapshot<W>>> {
+    let mut snapshots = Vec::new();
+    for round in 1..=rounds {
+        perform_round(monkeys)?;
+        if selection.contains(round) {
+            snapshots.push(RoundSnapshot::take(round, monkeys));
+        }
+    }
+    Ok(snapshots)
+}
+
+/// `modulus` is what the worry levels were kept modulo, if anything, so reduced values
+/// aren't mistaken for the real ones
+fn format_snapshots<W: Worry>(snapshots: &[RoundSnapshot<W>], format: ReportFormat, modulus: Option<i64>) -> String {
+    let mut out = String::new();
+    match format {
+        ReportFormat::Puzzle => {
+            let modulus = modulus.map(|modulus| format!(" (mod {})", modulus)).unwrap_or_default();
+            for snapshot in snapshots {
+                writeln!(
+                    out, "After round {}, the monkeys are holding items with these worry levels{}:",
+                    snapshot.round, modulus,
+                ).unwrap();
+                for (i, items) in snapshot.items.iter().enumerate() {
+                    let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                    writeln!(out, "Monkey {}: {}", i, items.join(", ")).unwrap();
+                }
+                writeln!(out, "\n== After round {} ==", snapshot.round).unwrap();
+                for (i, inspections) in snapshot.inspections.iter().enumerate() {
+                    writeln!(out, "Monkey {} inspected items {} times.", i, inspections).unwrap();
+                }
+                writeln!(out).unwrap();
+            }
+        },
+        ReportFormat::Csv => {
+            let modulus = modulus.map(|modulus| format!("_mod_{}", modulus)).unwrap_or_default();
+            writeln!(out, "round,monkey,inspections,items{}", modulus).unwrap();
+            for snapshot in snapshots {
+                for (i, (items, inspections)) in snapshot.items.iter().zip(snapshot.inspections.iter()).enumerate() {
+                    let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+                    writeln!(out, "{},{},{},{}", snapshot.round, i, inspections, items.join(" ")).unwrap();
+                }
+            }
+        },
+    }
+    out
+}
+
+/// Round by round report of a simulation, to diff against the puzzle's worked example.
+/// When the machine backend keeps worry levels modulo the divisors, the items are
+/// labelled with the modulus.
+pub fn report<P: AsRef<Path>>(
+    path: P,
+    rounds: usize,
+    relief: bool,
+    backend: Backend,
+    selection: &RoundSelection,
+    format: ReportFormat,
+) -> AOCResult<String> {
+    selection.check(rounds)?;
+    match backend {
+        Backend::Machine => {
+            let mut monkeys = load::<i64, P>(path, relief, true);
+            let modulus = monkeys.first().and_then(|monkey| monkey.modulo_space);
+            Ok(format_snapshots(&snapshots(&mut monkeys, rounds, selection)?, format, modulus))
+        },
+        Backend::Exact => {
+            let mut monkeys = load::<BigInt, P>(path, relief, false);
+            Ok(format_snapshots(&snapshots(&mut monkeys, rounds, selection)?, format, None))
+        },
+    }
+}
+
+#[test]
+fn test_report_matches_worked_example() {
+    let path = "res/day11/input.sample";
+    let selection = RoundSelection::Listed(vec![1]);
+    let part_1 = report(path, 1, true, Backend::Machine, &selection, ReportFormat::Puzzle).unwrap();
+    assert!(part_1.starts_with("\
+After round 1, the monkeys are holding items with these worry levels:
+Monkey 0: 20, 23, 27, 26
+Monkey 1: 2080, 25, 167, 207, 401, 1046
+Monkey 2: 
+Monkey 3: 
+"));
+
+    let selection: RoundSelection = "20,1000".parse().unwrap();
+    let part_2 = report(path, 1000, false, Backend::Machine, &selection, ReportFormat::Csv).unwrap();
+    assert!(part_2.starts_with("round,monkey,inspections,items_mod_96577\n"));
+    let inspections: Vec<&str> = part_2.lines()
+        .skip(1)
+        .map(|line| line.split(',').nth(2).unwrap())
+        .collect();
+    assert_eq!(inspections, vec!["99", "97", "8", "103", "5204", "4792", "199", "5192"]);
+    assert!("every:0".parse::<RoundSelection>().is_err());
+    assert!(report(path, 20, false, Backend::Machine, &"20,21".parse().unwrap(), ReportFormat::Csv).is_err());
+}
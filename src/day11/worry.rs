@@ -0,0 +1,76 @@
+use std::{cmp::Ordering, fmt::{Debug, Display}, str::FromStr};
+
+use crate::utils::{AOCError, bigint::BigInt};
+
+use super::expr::BinaryOp;
+
+/// A number type the monkeys can keep worry levels in.
+pub trait Worry: Clone + Debug + Display {
+    fn constant(value: i64) -> Self;
+    fn apply(op: BinaryOp, lhs: &Self, rhs: &Self) -> Self;
+    fn cmp_i64(&self, value: i64) -> Ordering;
+}
+
+impl Worry for i64 {
+    fn constant(value: i64) -> Self {
+        value
+    }
+
+    /// Panics rather than wrapping, since a wrapped worry level silently gives the wrong
+    /// answer. Dividing by zero is caught before getting here.
+    fn apply(op: BinaryOp, lhs: &Self, rhs: &Self) -> Self {
+        let result = match op {
+            BinaryOp::Add => lhs.checked_add(*rhs),
+            BinaryOp::Subtract => lhs.checked_sub(*rhs),
+            BinaryOp::Multiply => lhs.checked_mul(*rhs),
+            BinaryOp::Divide => lhs.checked_div(*rhs),
+            BinaryOp::Remainder => lhs.checked_rem(*rhs),
+        };
+        result.unwrap_or_else(|| panic!("{} {} {} doesn't fit in an i64, try the big backend", lhs, op.symbol(), rhs))
+    }
+
+    fn cmp_i64(&self, value: i64) -> Ordering {
+        self.cmp(&value)
+    }
+}
+
+impl Worry for BigInt {
+    fn constant(value: i64) -> Self {
+        BigInt::from(value)
+    }
+
+    fn apply(op: BinaryOp, lhs: &Self, rhs: &Self) -> Self {
+        match op {
+            BinaryOp::Add => lhs + rhs,
+            BinaryOp::Subtract => lhs - rhs,
+            BinaryOp::Multiply => lhs * rhs,
+            BinaryOp::Divide => lhs / rhs,
+            BinaryOp::Remainder => lhs % rhs,
+        }
+    }
+
+    fn cmp_i64(&self, value: i64) -> Ordering {
+        self.cmp(&BigInt::from(value))
+    }
+}
+
+/// Which `Worry` type to simulate with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// `i64`, kept small with the product of the divisors when that's valid
+    Machine,
+    /// exact arbitrary precision arithmetic, never reduced
+    Exact,
+}
+
+impl FromStr for Backend {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "i64" => Ok(Backend::Machine),
+            "big" => Ok(Backend::Exact),
+            _ => Err(AOCError::new(format!("unknown backend {}, expected i64 or big", s))),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::{cmp::Ordering, fmt::Write, path::Path};
+
+use super::{Element, load};
+
+fn verdict(ordering: Ordering) -> &'static str {
+    match ordering {
+        Ordering::Less => "so inputs are in the right order",
+        _ => "so inputs are not in the right order",
+    }
+}
+
+/// Compares like `Ord` does, writing a line per comparison at `depth` and the deciding step
+/// one level deeper.
+fn compare_traced(left: &Element, right: &Element, depth: usize, out: &mut String) -> Ordering {
+    let indent = "  ".repeat(depth);
+    writeln!(out, "{}- Compare {} vs {}", indent, left, right).unwrap();
+    match (left, right) {
+        (Element::Integer(this), Element::Integer(that)) => {
+            let ordering = this.cmp(that);
+            let side = match ordering {
+                Ordering::Less => Some("Left"),
+                Ordering::Greater => Some("Right"),
+                Ordering::Equal => None,
+            };
+            if let Some(side) = side {
+                writeln!(out, "{}  - {} side is smaller, {}", indent, side, verdict(ordering)).unwrap();
+            }
+            ordering
+        },
+        (Element::Integer(_), Element::List(_)) => {
+            let promoted = Element::List(vec![left.clone()]);
+            writeln!(out, "{}  - Mixed types; convert left to {} and retry comparison", indent, promoted).unwrap();
+            compare_traced(&promoted, right, depth + 1, out)
+        },
+        (Element::List(_), Element::Integer(_)) => {
+            let promoted = Element::List(vec![right.clone()]);
+            writeln!(out, "{}  - Mixed types; convert right to {} and retry comparison", indent, promoted).unwrap();
+            compare_traced(left, &promoted, depth + 1, out)
+        },
+        (Element::List(this), Element::List(that)) => {
+            for (left, right) in this.iter().zip(that.iter()) {
+                let ordering = compare_traced(left, right, depth + 1, out);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            let ordering = this.len().cmp(&that.len());
+            let side = match ordering {
+                Ordering::Less => Some("Left"),
+                Ordering::Greater => Some("Right"),
+                Ordering::Equal => None,
+            };
+            if let Some(side) = side {
+                writeln!(out, "{}  - {} side ran out of items, {}", indent, side, verdict(ordering)).unwrap();
+            }
+            ordering
+        },
+    }
+}
+
+impl Element {
+    /// Trace of how this packet compares to `other`, as a tree in the puzzle's format.
+    pub fn explain(&self, other: &Element) -> String {
+        let mut out = String::new();
+        compare_traced(self, other, 0, &mut out);
+        out
+    }
+}
+
+/// Explains every pair in `path`, or just the (1 indexed) pair asked for.
+pub fn explain_pairs<P: AsRef<Path>>(path: P, pair: Option<usize>) -> String {
+    load(path).iter()
+        .enumerate()
+        .map(|(i, pair)| (i + 1, pair))
+        .filter(|(number, _)| pair.is_none_or(|pair| pair == *number))
+        .map(|(number, (left, right))| format!("== Pair {} ==\n{}", number, left.explain(right)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[test]
+fn test_explain_matches_worked_example() {
+    let explained = explain_pairs("res/day13/input.sample", None);
+    let pairs: Vec<&str> = explained.split("\n\n").collect();
+    assert_eq!(pairs.len(), 8);
+    assert_eq!(pairs[1], "\
+== Pair 2 ==
+- Compare [[1],[2,3,4]] vs [[1],4]
+  - Compare [1] vs [1]
+    - Compare 1 vs 1
+  - Compare [2,3,4] vs 4
+    - Mixed types; convert right to [4] and retry comparison
+    - Compare [2,3,4] vs [4]
+      - Compare 2 vs 4
+        - Left side is smaller, so inputs are in the right order");
+    assert_eq!(pairs[2], "\
+== Pair 3 ==
+- Compare [9] vs [[8,7,6]]
+  - Compare 9 vs [8,7,6]
+    - Mixed types; convert left to [9] and retry comparison
+    - Compare [9] vs [8,7,6]
+      - Compare 9 vs 8
+        - Right side is smaller, so inputs are not in the right order");
+    assert_eq!(pairs[3], "\
+== Pair 4 ==
+- Compare [[4,4],4,4] vs [[4,4],4,4,4]
+  - Compare [4,4] vs [4,4]
+    - Compare 4 vs 4
+    - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Compare 4 vs 4
+  - Left side ran out of items, so inputs are in the right order");
+    assert_eq!(explain_pairs("res/day13/input.sample", Some(7)), "\
+== Pair 7 ==
+- Compare [[[]]] vs [[]]
+  - Compare [[]] vs []
+    - Right side ran out of items, so inputs are not in the right order
+");
+}
@@ -0,0 +1,231 @@
+use std::{cmp::Ordering, fmt::Write, fs, ops::Range, path::Path, time::Instant};
+
+use crate::utils::{AOCError, AOCResult};
+
+use super::{Element, decoder_key, synthetic_packets, parse::{ParseError, parse_pairs}};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Token {
+    Open,
+    Close,
+    Integer(i32),
+}
+
+/// Reads tokens straight out of a packet's text. A promoted integer is handed back as the
+/// integer followed by as many `Close`s as it was wrapped in lists, so comparing a packet
+/// never builds anything.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    promoted: Option<i32>,
+    pending_closes: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0, promoted: None, pending_closes: 0 }
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        if let Some(value) = self.promoted.take() {
+            return Some(Token::Integer(value));
+        }
+        if self.pending_closes > 0 {
+            self.pending_closes -= 1;
+            return Some(Token::Close);
+        }
+        // commas only separate, so they can be skipped
+        while self.bytes.get(self.position) == Some(&b',') {
+            self.position += 1;
+        }
+        let token = match self.bytes.get(self.position)? {
+            b'[' => Token::Open,
+            b']' => Token::Close,
+            _ => {
+                let mut value = 0;
+                while let Some(digit) = self.bytes.get(self.position).filter(|byte| byte.is_ascii_digit()) {
+                    value = value * 10 + (digit - b'0') as i32;
+                    self.position += 1;
+                }
+                return Some(Token::Integer(value));
+            },
+        };
+        self.position += 1;
+        Some(token)
+    }
+
+    /// treats an integer that was just read as a list holding only it
+    fn promote(&mut self, value: i32) {
+        self.promoted = Some(value);
+        self.pending_closes += 1;
+    }
+}
+
+/// Compares two packets in their text form, the same way `Element`'s `Ord` does.
+/// Stops reading as soon as the order is decided.
+fn compare(left: &[u8], right: &[u8]) -> Ordering {
+    let (mut left, mut right) = (Cursor::new(left), Cursor::new(right));
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (Some(Token::Integer(this)), Some(Token::Integer(that))) if this != that => return this.cmp(&that),
+            (Some(Token::Integer(this)), Some(Token::Open)) => left.promote(this),
+            (Some(Token::Open), Some(Token::Integer(that))) => right.promote(that),
+            (Some(Token::Close), Some(Token::Close)) | (Some(Token::Open), Some(Token::Open)) => {},
+            (Some(Token::Integer(_)), Some(Token::Integer(_))) => {},
+            (Some(Token::Close) | None, _) => return Ordering::Less,
+            (_, Some(Token::Close) | None) => return Ordering::Greater,
+        }
+    }
+}
+
+/// Checks that a line is a well formed packet without building it, so `compare` can
+/// trust its input.
+fn validate(line: &[u8]) -> Result<(), ParseError> {
+    let error = |position: usize, message: &str| Err(ParseError { position, message: message.to_string() });
+    let mut depth = 0usize;
+    // true where a value is allowed next, i.e. after '[' or ','
+    let mut expecting_value = true;
+    let mut position = 0;
+    while position < line.len() {
+        match line[position] {
+            b'[' if expecting_value => depth += 1,
+            b']' if depth > 0 && (!expecting_value || line[position - 1] == b'[') => {
+                depth -= 1;
+                expecting_value = false;
+            },
+            b',' if depth > 0 && !expecting_value => expecting_value = true,
+            byte if byte.is_ascii_digit() && expecting_value => {
+                let start = position;
+                let mut value = Some((byte - b'0') as i32);
+                while let Some(digit) = line.get(position + 1).filter(|byte| byte.is_ascii_digit()) {
+                    value = value.and_then(|value| value.checked_mul(10)?.checked_add((digit - b'0') as i32));
+                    position += 1;
+                }
+                if value.is_none() {
+                    return error(start, "number is too big");
+                }
+                expecting_value = false;
+            },
+            _ => return error(position, "unexpected character"),
+        }
+        if depth == 0 && position + 1 < line.len() {
+            return error(position + 1, "expected the end of the packet");
+        }
+        position += 1;
+    }
+    match (depth, expecting_value) {
+        (0, false) => Ok(()),
+        _ => error(line.len(), "packet ends early"),
+    }
+}
+
+/// Every packet of an input kept in one buffer, each one just a range of it.
+pub struct PacketArena {
+    text: Vec<u8>,
+    packets: Vec<Range<usize>>,
+}
+
+impl PacketArena {
+    pub fn new(text: String) -> AOCResult<Self> {
+        let mut packets = Vec::new();
+        let mut start = 0;
+        for (i, line) in text.split('\n').enumerate() {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                validate(trimmed.as_bytes()).map_err(|err| AOCError::new(format!("line {}, {}", i + 1, err)))?;
+                let offset = start + (trimmed.as_ptr() as usize - line.as_ptr() as usize);
+                packets.push(offset..offset + trimmed.len());
+            }
+            start += line.len() + 1;
+        }
+        if packets.len() % 2 == 1 {
+            return Err(AOCError::new(format!("{} packets can't be split into pairs", packets.len())));
+        }
+        Ok(Self { text: text.into_bytes(), packets })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> AOCResult<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|err| AOCError::new(format!("couldn't read {}: {}", path.display(), err)))?;
+        Self::new(text)
+    }
+
+    fn packet(&self, i: usize) -> &[u8] {
+        &self.text[self.packets[i].clone()]
+    }
+
+    fn num_packets(&self) -> usize {
+        self.packets.len()
+    }
+
+    /// sum of the (1 indexed) numbers of the pairs that are in the right order
+    pub fn sum_of_ordered_pairs(&self) -> usize {
+        (0..self.num_packets() / 2)
+            .filter(|pair| compare(self.packet(2 * pair), self.packet(2 * pair + 1)) == Ordering::Less)
+            .map(|pair| pair + 1)
+            .sum()
+    }
+
+    /// Where the dividers would end up if the packets were sorted with them. Only needs to
+    /// count what sorts before each divider, so nothing gets sorted or copied.
+    pub fn decoder_key(&self) -> usize {
+        let before = |divider: &[u8]| (0..self.num_packets())
+            .filter(|i| compare(self.packet(*i), divider) == Ordering::Less)
+            .count();
+        (before(b"[[2]]") + 1) * (before(b"[[6]]") + 2)
+    }
+}
+
+/// Times both parts on a generated input with the `Element` tree against the flat arena.
+pub fn benchmark(num_pairs: usize, depth: usize) -> String {
+    let text = synthetic_packets(num_pairs, depth);
+    let mut out = String::new();
+    writeln!(out, "{} pairs, {} bytes", num_pairs, text.len()).unwrap();
+
+    let start = Instant::now();
+    let pairs = parse_pairs(text.lines().map(String::from)).expect("generated packets parse");
+    let parsed = start.elapsed();
+    let ordered: usize = pairs.iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(i, _)| i + 1)
+        .sum();
+    let packets: Vec<Element> = pairs.into_iter().flat_map(|(left, right)| [left, right]).collect();
+    let key = decoder_key(&packets);
+    writeln!(out, "tree : {} and {} in {:?} ({:?} parsing)", ordered, key, start.elapsed(), parsed).unwrap();
+
+    let start = Instant::now();
+    let arena = PacketArena::new(text).expect("generated packets parse");
+    let parsed = start.elapsed();
+    let (ordered, key) = (arena.sum_of_ordered_pairs(), arena.decoder_key());
+    writeln!(out, "flat : {} and {} in {:?} ({:?} indexing)", ordered, key, start.elapsed(), parsed).unwrap();
+    out
+}
+
+#[test]
+fn test_flat_matches_tree() {
+    let text = synthetic_packets(300, 5);
+    let pairs = parse_pairs(text.lines().map(String::from)).unwrap();
+    let arena = PacketArena::new(text).unwrap();
+    for (i, (left, right)) in pairs.iter().enumerate() {
+        assert_eq!(compare(arena.packet(2 * i), arena.packet(2 * i + 1)), left.cmp(right));
+        assert_eq!(compare(arena.packet(2 * i + 1), arena.packet(2 * i)), right.cmp(left));
+    }
+    // promotion several lists deep on either side
+    assert_eq!(compare(b"[3]", b"[[[3]],1]"), Ordering::Less);
+    assert_eq!(compare(b"[[[[3]]]]", b"[3]"), Ordering::Equal);
+    assert_eq!(compare(b"[[[3,1]]]", b"[3]"), Ordering::Greater);
+
+    let arena = PacketArena::load("res/day13/input.sample").unwrap();
+    assert_eq!((arena.sum_of_ordered_pairs(), arena.decoder_key()), (13, 140));
+    for bad in ["[1,,2]", "[1,[2]", "[1]]", "[1 2]", "[]1", "[,1]", "[1,]", "1,2"] {
+        assert!(validate(bad.as_bytes()).is_err(), "{} should be rejected", bad);
+        assert!(bad.parse::<Element>().is_err());
+    }
+    assert_eq!(validate(b"[2147483647]"), Ok(()));
+    assert_eq!(validate(b"[2147483648]").unwrap_err().position, 1);
+    assert!(PacketArena::new("[1]\n[2]\n\n[3]\n".to_string()).is_err());
+    assert!(PacketArena::new("[1]\n[2,]\n".to_string()).err().is_some_and(|err| err.to_string().contains("line 2")));
+}
@@ -0,0 +1,74 @@
+use std::path::Path;
+
+use crate::utils::{self, AOCError, AOCResult, json::Json};
+
+use super::{Element, load};
+
+impl From<&Element> for Json {
+    fn from(element: &Element) -> Self {
+        match element {
+            Element::Integer(value) => Json::Number(*value as f64),
+            Element::List(elements) => Json::Array(elements.iter().map(Json::from).collect()),
+        }
+    }
+}
+
+impl TryFrom<&Json> for Element {
+    type Error = AOCError;
+
+    /// only arrays and whole numbers that fit in an `i32` make packets
+    fn try_from(json: &Json) -> Result<Self, Self::Error> {
+        match json {
+            Json::Number(value) if value.fract() == 0.0 && *value >= i32::MIN as f64 && *value <= i32::MAX as f64 => {
+                Ok(Element::Integer(*value as i32))
+            },
+            Json::Array(values) => values.iter()
+                .map(Element::try_from)
+                .collect::<AOCResult<Vec<Element>>>()
+                .map(Element::List),
+            _ => Err(AOCError::new(format!("{} isn't part of a packet", json))),
+        }
+    }
+}
+
+/// the pairs of packets in `path` as a JSON array of two element arrays
+pub fn packets_to_json<P: AsRef<Path>>(path: P) -> String {
+    let pairs = load(path).iter()
+        .map(|(left, right)| Json::Array(vec![left.into(), right.into()]))
+        .collect();
+    Json::Array(pairs).to_string()
+}
+
+/// Reads a JSON array of pairs like `packets_to_json` writes, and gives them back in the
+/// puzzle's input format.
+pub fn packets_from_json<P: AsRef<Path>>(path: P) -> AOCResult<String> {
+    let text: String = utils::read_input(path).collect::<Vec<String>>().join("\n");
+    let pairs = match text.parse()? {
+        Json::Array(pairs) => pairs,
+        json => return Err(AOCError::new(format!("expected an array of pairs, got {}", json))),
+    };
+    let pairs = pairs.iter()
+        .map(|pair| match pair {
+            Json::Array(pair) if pair.len() == 2 => {
+                Ok(format!("{}\n{}", Element::try_from(&pair[0])?, Element::try_from(&pair[1])?))
+            },
+            _ => Err(AOCError::new(format!("expected a pair of packets, got {}", pair))),
+        })
+        .collect::<AOCResult<Vec<String>>>()?;
+    Ok(pairs.join("\n\n"))
+}
+
+#[test]
+fn test_round_trips() {
+    for line in super::synthetic_packets(250, 6).lines().filter(|line| !line.is_empty()) {
+        let packet: Element = line.parse().unwrap();
+        let printed = packet.to_string();
+        assert_eq!(printed, line);
+        assert_eq!(printed.parse::<Element>().unwrap().to_string(), printed);
+        let json = Json::from(&packet).to_string();
+        let from_json = Element::try_from(&json.parse::<Json>().unwrap()).unwrap();
+        assert_eq!(from_json.to_string(), printed);
+    }
+    assert!(Element::try_from(&"[1,\"two\"]".parse::<Json>().unwrap()).is_err());
+    assert!(Element::try_from(&"[1.5]".parse::<Json>().unwrap()).is_err());
+}
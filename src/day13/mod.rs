@@ -1,540 +1,168 @@
-use std::path::Path;
+use std::{cmp::Ordering, fmt::{Display, Write}, path::Path};
 
-use crate::utils;
+use crate::utils::{self, lcg::Lcg};
 
-#[derive(PartialEq, Eq, Ord, Clone)]
+mod explain;
+mod flat;
+mod json;
+mod parse;
+
+pub use explain::explain_pairs;
+pub use flat::{PacketArena, benchmark};
+pub use json::{packets_to_json, packets_from_json};
+
+/// A packet, or a part of one. `==` compares structure, so `[[1],2]` and `[1,2]` aren't
+/// equal even though the packet order puts them level; use `cmp` for that.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Element {
     Integer(i32),
     List(Vec<Element>),
 }
 
-impl PartialOrd for Element {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+impl Ord for Element {
+    /// The puzzle's packet order. Integers compare as numbers and lists compare element by
+    /// element, with the shorter list first if one runs out. An integer compared to a list
+    /// is treated as a list holding just that integer.
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Element::Integer(this), Element::Integer(that)) => Some(this.cmp(that)),
-            (Element::Integer(this), Element::List(_)) => Element::List(vec![Element::Integer(*this)]).partial_cmp(other),
-            (Element::List(_), Element::Integer(that)) => self.partial_cmp(&Element::List(vec![Element::Integer(*that)])),
-            (Element::List(this), Element::List(that)) => {
-                return this.partial_cmp(that);
-                /*
-                for i in 0..this.len().max(that.len()) {
+            (Element::Integer(this), Element::Integer(that)) => this.cmp(that),
+            (Element::Integer(_), Element::List(that)) => std::slice::from_ref(self).cmp(that.as_slice()),
+            (Element::List(this), Element::Integer(_)) => this.as_slice().cmp(std::slice::from_ref(other)),
+            (Element::List(this), Element::List(that)) => this.cmp(that),
+        }
+    }
+}
+
+impl Display for Element {
+    /// the packet syntax the input uses, which parses back to the same packet
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Element::Integer(value) => write!(f, "{}", value),
+            Element::List(elements) => {
+                f.write_str("[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", element)?;
                 }
-                */
+                f.write_str("]")
             },
         }
     }
 }
 
-pub fn answer_part_1(data: Vec<(Element, Element)>) -> String {
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// the pairs of packets in `path`
+pub fn load<P: AsRef<Path>>(path: P) -> Vec<(Element, Element)> {
+    parse::parse_pairs(utils::read_input(path))
+        .unwrap_or_else(|err| panic!("couldn't read packets: {}", err))
+}
+
+pub fn answer_part_1<P: AsRef<Path>>(path: P) -> String {
+    let data = load(path);
     let num_in_right_order = data.into_iter()
         .enumerate()
-        .filter(|(i, pair)| pair.0 < pair.1)
+        .filter(|(_, pair)| pair.0 < pair.1)
         .map(|(i, _)| i + 1)
         .reduce(|a, b| a + b)
         .unwrap();
     num_in_right_order.to_string()
 }
 
-pub fn answer_part_2(data: Vec<(Element, Element)>) -> String {
-    let mut data: Vec<Element> = data.into_iter()
-        .flat_map(|pair| vec![pair.0, pair.1].into_iter())
-        .collect();
+/// Where the dividers would end up if they were sorted in with the packets. Packets that
+/// only compare equal to a divider, like `[2]`, aren't dividers, so only what sorts strictly
+/// before each one counts.
+fn decoder_key(packets: &[Element]) -> usize {
     let two = Element::List(vec![Element::Integer(2)]);
     let six = Element::List(vec![Element::Integer(6)]);
-    data.push(two.clone());
-    data.push(six.clone());
-    data.sort();
-    let decoder_key = data.into_iter()
-        .enumerate()
-        .filter(|(_, elem)| elem == &two || elem == &six)
-        .map(|(i, _)| i + 1)
-        .reduce(|a, b| a * b)
-        .unwrap();
-    decoder_key.to_string()
+    let before = |divider: &Element| packets.iter().filter(|packet| *packet < divider).count();
+    (before(&two) + 1) * (before(&six) + 2)
 }
 
+pub fn answer_part_2<P: AsRef<Path>>(path: P) -> String {
+    let packets: Vec<Element> = load(path).into_iter()
+        .flat_map(|pair| [pair.0, pair.1])
+        .collect();
+    decoder_key(&packets).to_string()
+}
 
-pub fn data1() -> Vec<(Element, Element)> {
-    vec![
-        (Element::List(vec![Element::Integer(1),Element::Integer(1),Element::Integer(3),Element::Integer(1),Element::Integer(1)]),
-        Element::List(vec![Element::Integer(1),Element::Integer(1),Element::Integer(5),Element::Integer(1),Element::Integer(1)])),
-
-        (Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(4)])),
-
-        (Element::List(vec![Element::Integer(9)]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(6)])])),
-
-        (Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(4)]),Element::Integer(4),Element::Integer(4)]),
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(4)]),Element::Integer(4),Element::Integer(4),Element::Integer(4)])),
+/// Random packet text, nested at most `depth` deep.
+fn write_packet(out: &mut String, lcg: &mut Lcg, depth: usize) {
+    match depth > 0 && !lcg.next().is_multiple_of(3) {
+        true => {
+            out.push('[');
+            for i in 0..lcg.next() % 6 {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_packet(out, lcg, depth - 1);
+            }
+            out.push(']');
+        },
+        false => write!(out, "{}", lcg.next() % 11).unwrap(),
+    }
+}
 
-        (Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(7),Element::Integer(7)]),
-        Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(7)])),
+/// an input with `num_pairs` pairs of packets nested up to `depth` deep, the same on every run
+fn synthetic_packets(num_pairs: usize, depth: usize) -> String {
+    let mut lcg = Lcg::new(0x2022_0013);
+    let mut out = String::new();
+    for _ in 0..num_pairs {
+        for _ in 0..2 {
+            out.push('[');
+            write_packet(&mut out, &mut lcg, depth);
+            out.push_str("]\n");
+        }
+        out.push('\n');
+    }
+    out
+}
 
-        (Element::List(vec![]),
-        Element::List(vec![Element::Integer(3)])),
+#[test]
+fn test_packet_order() {
+    let pairs = load("res/day13/input.sample");
+    let in_order: Vec<bool> = pairs.iter().map(|(left, right)| left < right).collect();
+    // the puzzle's verdict on each of the example pairs
+    assert_eq!(in_order, vec![true, true, false, true, false, true, false, false]);
+    for (left, right) in pairs.iter() {
+        assert_eq!(left.cmp(right), right.cmp(left).reverse());
+        assert_eq!(left.cmp(left), Ordering::Equal);
+    }
 
-        (Element::List(vec![Element::List(vec![Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![])])),
+    // promotion goes both ways, and a promoted integer still loses to a longer list
+    let packet = |s: &str| s.parse::<Element>().unwrap();
+    assert_eq!(packet("[[1],2]").cmp(&packet("[1,2]")), Ordering::Equal);
+    assert_ne!(packet("[[1],2]"), packet("[1,2]"));
+    assert_eq!(packet("[3]").cmp(&packet("[[3,0]]")), Ordering::Less);
+    assert_eq!(packet("[[[]]]").cmp(&packet("[[]]")), Ordering::Greater);
 
-        (Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(7)])])])]),Element::Integer(8),Element::Integer(9)]),
-        Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(0)])])])]),Element::Integer(8),Element::Integer(9)])),
-    ]
+    // the sorted order from part 2 of the puzzle
+    let mut packets: Vec<Element> = pairs.into_iter().flat_map(|(left, right)| [left, right]).collect();
+    packets.extend([packet("[[2]]"), packet("[[6]]")]);
+    packets.sort();
+    let expected = [
+        "[]", "[[]]", "[[[]]]", "[1,1,3,1,1]", "[1,1,5,1,1]", "[[1],[2,3,4]]", "[1,[2,[3,[4,[5,6,0]]]],8,9]",
+        "[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[1],4]", "[[2]]", "[3]", "[[4,4],4,4]", "[[4,4],4,4,4]", "[[6]]",
+        "[7,7,7]", "[7,7,7,7]", "[[8,7,6]]", "[9]",
+    ];
+    assert_eq!(packets, expected.map(packet));
 }
 
-/*
-pub fn data2() -> Vec<(Element, Element)> {
-    vec![(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(6)]),Element::Integer(3),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(7),Element::Integer(9),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(10),Element::Integer(7),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::Integer(2),Element::Integer(9),Element::Integer(9),Element::Integer(2)]),Element::Integer(5),Element::List(vec![Element::Integer(1)])]),Element::Integer(5),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(6),Element::Integer(9),Element::Integer(1)]),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(8),Element::Integer(3)]),Element::Integer(9),Element::Integer(4),Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(10),Element::Integer(9),Element::Integer(7)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1)])]),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(1)]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(4)]),Element::Integer(10)])]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(7)]),Element::Integer(2),Element::Integer(9)]),Element::List(vec![Element::List(vec![]),Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(3),Element::Integer(6)]),Element::Integer(4),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(4),Element::Integer(7),Element::Integer(2)]),Element::Integer(3)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(9),Element::List(vec![Element::List(vec![])])]),Element::List(vec![]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(1),Element::Integer(5),Element::Integer(2)]),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(0),Element::Integer(7),Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(0)])]),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(7),Element::Integer(10),Element::Integer(6),Element::List(vec![Element::Integer(2),Element::Integer(10),Element::Integer(0),Element::Integer(10)])]),Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(7),Element::List(vec![]),Element::Integer(3),Element::List(vec![])]),Element::Integer(5),Element::Integer(2),Element::List(vec![])]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(8),Element::List(vec![])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(6),Element::Integer(0)]),Element::Integer(9),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(1)]),Element::Integer(0),Element::Integer(6),Element::List(vec![Element::Integer(4),Element::Integer(9)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7)]),Element::Integer(5),Element::Integer(10)]),Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::List(vec![Element::Integer(10)])]),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(4),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(6),Element::Integer(3),Element::Integer(3),Element::Integer(3),Element::Integer(2)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(2),Element::Integer(7)])]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::List(vec![])]),Element::Integer(10),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(7)]),Element::Integer(10)]),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(0),Element::Integer(4),Element::Integer(6)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(0)]),Element::Integer(10),Element::List(vec![Element::Integer(9),Element::Integer(5)]),Element::Integer(9)]),Element::Integer(4),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::List(vec![]),Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(8)]),Element::List(vec![Element::Integer(6)]),Element::Integer(3)]),Element::Integer(1),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(9),Element::Integer(1),Element::Integer(10)]),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(5),Element::Integer(4)]),Element::List(vec![Element::Integer(6)]),Element::Integer(8)]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(2)]),Element::Integer(8),Element::Integer(10)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![]),Element::Integer(9),Element::Integer(6),Element::List(vec![])]),Element::Integer(10),Element::List(vec![])]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5)]),Element::Integer(4),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(2)]),Element::List(vec![Element::Integer(7),Element::Integer(1),Element::Integer(1)])]),Element::Integer(6),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(8),Element::Integer(8),Element::Integer(8)]),Element::List(vec![Element::Integer(0),Element::Integer(1)])]),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(10),Element::Integer(3),Element::Integer(4)]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(1),Element::Integer(9),Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(3)]),Element::Integer(1),Element::List(vec![Element::Integer(8),Element::Integer(9)])])]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(7),Element::Integer(7)]),Element::Integer(5),Element::List(vec![Element::Integer(4)])]),Element::List(vec![]),Element::Integer(2)]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(8)]),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(4),Element::Integer(5),Element::Integer(3),Element::Integer(4)])]),Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(0),Element::Integer(8),Element::Integer(5)])]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(5)]),Element::Integer(0)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![]),Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(0),Element::Integer(6),Element::Integer(6)])]),Element::List(vec![Element::Integer(6),Element::Integer(7),Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(0),Element::Integer(3),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(3),Element::Integer(0)])]),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::Integer(6)]),Element::Integer(8)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(3),Element::Integer(3),Element::Integer(6)]),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(2),Element::Integer(6),Element::Integer(7)]),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(9),Element::Integer(6),Element::Integer(8)]),Element::Integer(3),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(9),Element::Integer(7)])])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(4),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(3),Element::Integer(9),Element::Integer(7),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(8),Element::Integer(6)]),Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(7)]),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(2),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(10)]),Element::Integer(5)]),Element::Integer(9),Element::Integer(6),Element::List(vec![Element::Integer(10),Element::List(vec![]),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(7),Element::Integer(5)]),Element::List(vec![Element::Integer(10)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(0)]),Element::Integer(5)]),Element::List(vec![Element::Integer(3),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3)]),Element::Integer(6),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(7),Element::Integer(8),Element::Integer(8)]),Element::Integer(3)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(5),Element::List(vec![Element::Integer(0)]),Element::Integer(6)]),Element::Integer(3),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::Integer(4),Element::Integer(1)]),Element::Integer(0)])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(0)]),Element::Integer(7),Element::List(vec![Element::Integer(0),Element::Integer(8)]),Element::Integer(4),Element::List(vec![])]),Element::Integer(8),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(9),Element::Integer(6),Element::Integer(3)]),Element::Integer(4)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::List(vec![]),Element::Integer(7),Element::List(vec![Element::Integer(7),Element::Integer(10),Element::Integer(1)]),Element::Integer(6),Element::Integer(0)]),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(8),Element::Integer(7),Element::Integer(9)]),Element::Integer(4),Element::Integer(8)])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(4),Element::Integer(1),Element::Integer(1)]),Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(6),Element::Integer(10),Element::Integer(2)]),Element::Integer(10),Element::List(vec![Element::Integer(4)])]),Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(2),Element::Integer(3)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(8)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(3)]),Element::Integer(2),Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(2),Element::Integer(2)]),Element::List(vec![Element::Integer(0),Element::Integer(1)])]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::Integer(9)]),Element::Integer(10),Element::Integer(7)]),Element::Integer(1)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(3),Element::Integer(9),Element::Integer(1)])]),Element::Integer(0)]),Element::List(vec![Element::Integer(4),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(10)]),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(6)])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(7)]),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(1),Element::List(vec![Element::Integer(0)]),Element::Integer(7),Element::List(vec![Element::Integer(10)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(2),Element::Integer(9)]),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(5)]),Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(9)])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(7)])]),Element::Integer(2)]),Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(2),Element::Integer(0),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(0),Element::Integer(3)]),Element::Integer(9)])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(5),Element::List(vec![Element::Integer(0)]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(1)]),Element::Integer(5)]),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(2),Element::Integer(3)]),Element::Integer(6),Element::List(vec![]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(8),Element::Integer(10),Element::Integer(5)]),Element::List(vec![Element::Integer(5)])]),Element::List(vec![Element::Integer(10),Element::Integer(2)])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)])])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(8),Element::Integer(5)]),Element::Integer(2),Element::List(vec![Element::Integer(0),Element::Integer(8)])]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::Integer(7),Element::Integer(8),Element::List(vec![])])]),Element::List(vec![Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::Integer(6)]),Element::Integer(3),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(4),Element::Integer(3),Element::Integer(3)])]),Element::Integer(2),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::List(vec![Element::Integer(1)])])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(2),Element::Integer(3)]),Element::Integer(7),Element::Integer(1)]),Element::List(vec![Element::Integer(10),Element::Integer(7),Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(2)]),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(7)]),Element::Integer(10),Element::Integer(1),Element::List(vec![Element::Integer(4)])]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(0),Element::Integer(5)]),Element::List(vec![Element::Integer(1),Element::Integer(10)])])]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(5),Element::Integer(0),Element::Integer(4)]),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(8),Element::Integer(5),Element::Integer(3)]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(10),Element::Integer(0),Element::Integer(4)]),Element::Integer(4)]),Element::Integer(8),Element::List(vec![Element::Integer(8),Element::List(vec![])])]),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(0)])]),
-        Element::List(vec![Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::Integer(1)]),Element::Integer(10),Element::Integer(5),Element::Integer(8)]),Element::Integer(10),Element::Integer(8)]),Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::Integer(2),Element::List(vec![Element::Integer(9),Element::Integer(7)])]),Element::Integer(9),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(5),Element::Integer(8),Element::Integer(0)]),Element::Integer(4)]),Element::Integer(2),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(8),Element::Integer(7),Element::Integer(9)]),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(9)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)])]),Element::List(vec![]),Element::Integer(1),Element::List(vec![]),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(10),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(9),Element::Integer(0)])]),Element::Integer(9),Element::List(vec![])]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::List(vec![Element::Integer(4),Element::Integer(1),Element::Integer(7),Element::List(vec![Element::Integer(7),Element::Integer(6),Element::Integer(3),Element::Integer(1),Element::Integer(4)]),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(5),Element::List(vec![Element::Integer(1),Element::Integer(7)]),Element::Integer(2),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(0)])])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![]),Element::Integer(1),Element::List(vec![]),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(1)])]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(3)])]),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(6),Element::Integer(10)])])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(9)]),Element::Integer(6),Element::List(vec![Element::Integer(8),Element::Integer(0)])]),Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(3),Element::Integer(4),Element::Integer(7)])]),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(9)]),Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(2)]),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(8)]),Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(5),Element::Integer(2),Element::List(vec![Element::Integer(7),Element::Integer(8),Element::Integer(9)])]),Element::Integer(8),Element::List(vec![]),Element::Integer(2)]),Element::List(vec![Element::Integer(7)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(0)]),Element::Integer(9)]),Element::Integer(3),Element::List(vec![]),Element::Integer(2)]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(7),Element::Integer(6),Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(1),Element::Integer(8),Element::Integer(10),Element::Integer(2)]),Element::Integer(10)]),Element::Integer(1),Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(10),Element::List(vec![Element::Integer(5)])])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(2)])]),Element::Integer(9),Element::Integer(7),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(6),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(4),Element::Integer(0)]),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(4),Element::Integer(1),Element::Integer(1)])]),Element::Integer(5)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7)])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(7),Element::Integer(7)]),Element::Integer(1)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(3)]),Element::List(vec![Element::Integer(3),Element::Integer(3),Element::List(vec![])]),Element::List(vec![]),Element::Integer(3),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::Integer(8)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(4)]),Element::List(vec![Element::Integer(6)]),Element::Integer(7)]),Element::List(vec![Element::Integer(1)]),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(0)]),Element::Integer(5),Element::List(vec![Element::Integer(7)]),Element::Integer(0),Element::Integer(0)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(5)]),Element::Integer(10)]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(8),Element::Integer(5)]),Element::List(vec![Element::Integer(9),Element::Integer(2),Element::Integer(7),Element::Integer(6),Element::Integer(4)]),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(1),Element::Integer(7)]),Element::Integer(8),Element::Integer(1),Element::List(vec![]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(3)]),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(1),Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8)]),Element::Integer(5),Element::Integer(10),Element::Integer(6),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(3),Element::Integer(10)])]),Element::Integer(7),Element::List(vec![Element::Integer(3)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(8),Element::Integer(4),Element::Integer(2)]),Element::Integer(0),Element::Integer(8),Element::Integer(3)]),Element::Integer(8),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![])]),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(4)]),Element::Integer(4)]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(0)]),Element::Integer(8),Element::Integer(8)]),Element::List(vec![])]),Element::List(vec![Element::Integer(10),Element::Integer(2)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(9),Element::Integer(10)]),Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(3)])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(7)]),Element::Integer(9)])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(4)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::List(vec![Element::Integer(3),Element::Integer(9),Element::Integer(0)]),Element::Integer(9)])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(0)])]),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(6)]),Element::Integer(4),Element::List(vec![Element::Integer(8)]),Element::Integer(7)]),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(3),Element::Integer(6),Element::Integer(4)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(9),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(6),Element::Integer(3),Element::Integer(1),Element::Integer(10),Element::Integer(9)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::Integer(7),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(8),Element::Integer(5),Element::Integer(3)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(7)]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(7),Element::Integer(6)]),Element::Integer(10),Element::Integer(10)])])]),
-        ),(
-        Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(9),Element::Integer(5),Element::Integer(8)]),
-        Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(9),Element::Integer(5)]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![]),Element::List(vec![Element::Integer(2)])])]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::Integer(9),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(0),Element::List(vec![Element::Integer(0)])]),Element::Integer(4)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(2),Element::Integer(0),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(6),Element::Integer(1),Element::Integer(8)]),Element::List(vec![])]),Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(10),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::Integer(3)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(3),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(1),Element::Integer(6),Element::Integer(0)]),Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(8),Element::Integer(8),Element::Integer(4)]),Element::Integer(6)]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(6)])]),Element::List(vec![])]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::Integer(4),Element::Integer(6),Element::List(vec![])]),Element::Integer(7),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::List(vec![Element::Integer(7),Element::Integer(8),Element::Integer(0)]),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(0),Element::Integer(7),Element::Integer(0)]),Element::Integer(9),Element::List(vec![Element::Integer(6)])]),Element::List(vec![Element::Integer(5),Element::Integer(9)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(2),Element::Integer(1)]),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(3),Element::Integer(7),Element::Integer(7)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(2),Element::Integer(6),Element::Integer(4)]),Element::List(vec![]),Element::Integer(10)]),Element::Integer(10),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(1),Element::Integer(6),Element::Integer(9)])]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(10),Element::Integer(3)]),Element::Integer(2),Element::Integer(1),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4)]),Element::List(vec![Element::Integer(3),Element::Integer(4)])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(7),Element::Integer(6),Element::Integer(6),Element::Integer(7)])]),Element::Integer(2)]),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(4)]),Element::Integer(10),Element::List(vec![Element::Integer(3),Element::Integer(3)])]),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(2),Element::Integer(1),Element::Integer(2)]),Element::List(vec![Element::Integer(0),Element::Integer(4)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![]),Element::List(vec![]),Element::Integer(0),Element::Integer(4)]),Element::Integer(2)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(8),Element::Integer(0)])]),Element::List(vec![Element::Integer(4)])]),Element::List(vec![Element::Integer(4),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(4),Element::Integer(4),Element::Integer(2)]),Element::Integer(6),Element::Integer(2),Element::Integer(7)]),Element::Integer(7),Element::Integer(7),Element::Integer(7),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(4)]),Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(2),Element::Integer(0)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(9),Element::Integer(9)]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::Integer(6),Element::Integer(4)]),Element::List(vec![Element::Integer(9),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![]),Element::Integer(3),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(9),Element::Integer(0),Element::Integer(10)]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(3),Element::Integer(9),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(0)]),Element::Integer(0),Element::List(vec![])])]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(5),Element::Integer(10),Element::Integer(4)]),Element::Integer(10),Element::Integer(2),Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::Integer(6),Element::List(vec![Element::Integer(5),Element::Integer(0)])]),Element::Integer(8),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(9)])])]),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(1),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(1)])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(10),Element::List(vec![Element::Integer(2)])]),Element::Integer(0),Element::List(vec![Element::Integer(8),Element::List(vec![])])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4),Element::Integer(6),Element::Integer(4),Element::Integer(1)]),Element::Integer(2),Element::Integer(2),Element::List(vec![Element::Integer(6),Element::Integer(2),Element::Integer(9),Element::Integer(5),Element::Integer(2)]),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(8),Element::Integer(10)]),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(2),Element::Integer(7),Element::Integer(4)]),Element::Integer(2)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(6),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(8)]),Element::List(vec![Element::Integer(7),Element::Integer(1)]),Element::List(vec![Element::Integer(2)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::Integer(6),Element::Integer(4)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(7),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(9),Element::Integer(9)])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(1),Element::Integer(4),Element::Integer(10)]),Element::Integer(0)]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::Integer(2),Element::List(vec![Element::Integer(1),Element::Integer(7),Element::Integer(3),Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::Integer(4),Element::Integer(5),Element::Integer(9)]),Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(6),Element::Integer(7),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(5)]),Element::Integer(9),Element::List(vec![Element::Integer(10),Element::Integer(9)]),Element::Integer(7)]),Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(9),Element::Integer(7),Element::Integer(6)]),Element::Integer(6),Element::List(vec![Element::Integer(4)])]),Element::Integer(10)]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(0)]),Element::Integer(9),Element::Integer(7),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(8),Element::Integer(2),Element::Integer(8)])]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(1)])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(1),Element::Integer(10)])]),Element::Integer(10)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(9),Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(9)]),Element::List(vec![])])]),Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(7),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(1)]),Element::Integer(2),Element::Integer(5),Element::Integer(3)]),Element::Integer(10),Element::Integer(6)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(2),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(2),Element::Integer(1),Element::Integer(10),Element::Integer(1)]),Element::Integer(1),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(9)]),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(1),Element::Integer(7),Element::Integer(7)]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(7)])]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(1),Element::Integer(9),Element::Integer(5)])]),Element::List(vec![]),Element::List(vec![]),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(10)]),Element::List(vec![Element::Integer(5),Element::Integer(9)]),Element::List(vec![Element::Integer(6),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(1),Element::Integer(7)])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(0),Element::Integer(2),Element::Integer(2)]),Element::List(vec![Element::Integer(4),Element::Integer(7),Element::Integer(1)]),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(5)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::Integer(8)]),Element::List(vec![Element::Integer(3)])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::Integer(10),Element::Integer(0),Element::Integer(7)]),Element::Integer(3),Element::Integer(1)]),Element::Integer(5),Element::List(vec![Element::Integer(9),Element::Integer(10),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(10),Element::Integer(6)])]),Element::Integer(6)]),Element::List(vec![Element::Integer(7),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(8),Element::Integer(10),Element::Integer(5)]),Element::Integer(8),Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(5),Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(1)]),Element::List(vec![Element::Integer(0),Element::Integer(5),Element::Integer(7)])])]),Element::List(vec![Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(8)])])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(3),Element::List(vec![])]),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![])]),Element::Integer(2),Element::List(vec![])]),Element::List(vec![Element::Integer(9),Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::List(vec![Element::Integer(2),Element::Integer(10)]),Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(6)]),Element::Integer(8),Element::Integer(4),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(7)]),Element::Integer(7),Element::Integer(9),Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(0)]),Element::Integer(2),Element::Integer(8),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(8),Element::Integer(3)])]),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(9),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(4),Element::Integer(3)])]),Element::Integer(7),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(0),Element::Integer(4)])])]),Element::List(vec![Element::List(vec![]),Element::Integer(2)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::Integer(10),Element::Integer(5),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(6)]),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(5),Element::Integer(10),Element::Integer(9)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(6),Element::Integer(0)])]),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(2)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(4)]),Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(1),Element::Integer(6),Element::Integer(8)]),Element::List(vec![Element::Integer(7),Element::Integer(10)]),Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(3)]),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(6),Element::Integer(3)]),Element::List(vec![]),Element::Integer(4)])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(4),Element::Integer(7),Element::Integer(7)]),Element::Integer(1),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(5),Element::Integer(3)]),Element::Integer(9),Element::Integer(4),Element::Integer(9)]),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(5),Element::Integer(10),Element::Integer(8)]),Element::Integer(2),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(7)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::Integer(7),Element::Integer(1),Element::Integer(3),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(7),Element::Integer(0),Element::Integer(10)]),Element::Integer(0),Element::List(vec![Element::Integer(5),Element::Integer(9)])]),Element::Integer(0),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3),Element::Integer(8)]),Element::Integer(0)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(10),Element::Integer(9),Element::Integer(9),Element::Integer(8)])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(9)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(3),Element::Integer(4),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::Integer(8),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(7),Element::Integer(0),Element::Integer(9)])]),Element::Integer(10),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(1),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(10)])]),Element::Integer(7)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![]),Element::Integer(6),Element::Integer(8),Element::Integer(10)]),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::Integer(10),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(7),Element::Integer(4)]),Element::Integer(6)]),Element::List(vec![Element::Integer(7),Element::Integer(2),Element::Integer(2),Element::List(vec![Element::Integer(0),Element::Integer(4),Element::Integer(7)])])]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(2)]),Element::List(vec![])]),Element::List(vec![]),Element::Integer(2),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(8),Element::Integer(9)]),Element::List(vec![Element::Integer(3),Element::Integer(0),Element::Integer(10),Element::Integer(10)])])]),Element::List(vec![Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(3)]),Element::List(vec![Element::Integer(2)]),Element::Integer(8)])]),Element::List(vec![Element::Integer(3)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(1),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(4)]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(7),Element::Integer(5),Element::Integer(9)]),Element::Integer(1)]),Element::Integer(1),Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(1),Element::List(vec![Element::Integer(4),Element::Integer(4),Element::Integer(3),Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(7),Element::Integer(4)]),Element::Integer(8),Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(0)])]),Element::Integer(5),Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(8)]),Element::List(vec![Element::Integer(5),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::Integer(6),Element::Integer(2),Element::Integer(6),Element::Integer(6)])])]),Element::List(vec![Element::Integer(6),Element::List(vec![]),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(6)]),Element::Integer(8)]),Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(5),Element::Integer(2),Element::Integer(4)]),Element::List(vec![]),Element::Integer(3),Element::Integer(1)]),Element::Integer(5),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(7),Element::Integer(6)]),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(1)])]),Element::Integer(6),Element::Integer(6)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(8),Element::Integer(10),Element::Integer(2)])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(10)]),Element::List(vec![Element::Integer(6)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(1)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(2),Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(4)])]),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::List(vec![Element::Integer(7)])]),Element::List(vec![Element::Integer(4)])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(8),Element::Integer(7),Element::Integer(2)]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(3),Element::Integer(6)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(6),Element::Integer(0)]),Element::Integer(10)]),Element::Integer(0)]),Element::List(vec![Element::Integer(1)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![]),Element::Integer(8),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(8),Element::Integer(7)]),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(7),Element::Integer(9)])]),Element::Integer(3),Element::List(vec![]),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(7),Element::Integer(6)]),Element::List(vec![Element::Integer(1),Element::List(vec![]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(3),Element::Integer(2)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(8),Element::Integer(9),Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(0)]),Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(8),Element::Integer(0),Element::Integer(10)]),Element::Integer(1),Element::Integer(6)]),Element::Integer(5),Element::Integer(4),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(1),Element::Integer(8)]),Element::List(vec![]),Element::Integer(3),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(4),Element::Integer(1),Element::Integer(10)])]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1)]),Element::Integer(10),Element::List(vec![]),Element::Integer(5)]),Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(9),Element::Integer(0),Element::Integer(9)]),Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(4),Element::Integer(6)])]),Element::Integer(4)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(1),Element::Integer(2),Element::Integer(4)]),Element::Integer(0),Element::List(vec![Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(4),Element::Integer(9),Element::Integer(9),Element::Integer(4)])]),Element::Integer(1),Element::Integer(7),Element::List(vec![Element::Integer(1),Element::Integer(1)]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4)])]),Element::List(vec![])]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![]),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(0)]),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(9),Element::Integer(1)]),Element::Integer(7),Element::Integer(9)])]),Element::List(vec![Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(0),Element::List(vec![Element::Integer(7)])]),Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(8),Element::Integer(9),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(3)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(9),Element::Integer(1),Element::Integer(6)])]),Element::Integer(6)]),Element::List(vec![Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(0),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(1),Element::Integer(9)]),Element::Integer(4),Element::Integer(0)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(9)]),Element::List(vec![Element::Integer(5),Element::Integer(1),Element::Integer(10)])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(6)])]),Element::Integer(5),Element::Integer(2),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(5),Element::Integer(2),Element::Integer(6),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(9),Element::Integer(4),Element::Integer(7)]),Element::Integer(2),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(2)])]),Element::Integer(2),Element::Integer(1),Element::Integer(6),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(3)]),Element::Integer(10),Element::Integer(2),Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(10)])]),Element::Integer(2),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::Integer(9),Element::List(vec![]),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(6),Element::Integer(6)])]),Element::Integer(1)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(9),Element::Integer(10),Element::Integer(5),Element::Integer(1)])]),Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(2),Element::Integer(8),Element::Integer(8)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5)]),Element::Integer(0),Element::List(vec![Element::Integer(7),Element::Integer(1),Element::Integer(3),Element::Integer(0),Element::Integer(3)]),Element::List(vec![Element::Integer(3)])])]),Element::List(vec![Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8)]),Element::List(vec![Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(0)]),Element::List(vec![Element::Integer(9),Element::Integer(4),Element::Integer(10),Element::Integer(4),Element::Integer(3)])]),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(2),Element::Integer(9),Element::Integer(5)]),Element::Integer(8),Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(8)]),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![]),Element::Integer(6),Element::Integer(0)]),Element::List(vec![Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::List(vec![Element::Integer(5),Element::Integer(1),Element::Integer(3)])]),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(2),Element::Integer(3),Element::Integer(10)])]),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(8),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(8),Element::Integer(6),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(6)]),Element::Integer(10),Element::Integer(0),Element::List(vec![Element::Integer(0)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::Integer(1)]),Element::Integer(0),Element::List(vec![Element::Integer(5),Element::Integer(7)]),Element::Integer(0),Element::Integer(0)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(6),Element::Integer(1),Element::List(vec![Element::Integer(6),Element::Integer(7)])]),Element::Integer(3)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![]),Element::Integer(7)]),Element::Integer(7),Element::Integer(5)]),Element::List(vec![Element::Integer(2),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(3),Element::Integer(8)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(7),Element::Integer(9),Element::Integer(8),Element::Integer(1)])]),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::List(vec![]),Element::Integer(9),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(3),Element::Integer(2)])])]),Element::List(vec![Element::Integer(6),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(5)])]),Element::Integer(3),Element::Integer(0)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(8),Element::List(vec![Element::Integer(9),Element::Integer(5)]),Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(3),Element::Integer(7),Element::Integer(6)])]),Element::List(vec![Element::Integer(4)]),Element::Integer(4),Element::List(vec![]),Element::Integer(6)]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(7),Element::Integer(6)]),Element::Integer(2)]),Element::List(vec![Element::Integer(6),Element::Integer(3)]),Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(1)])]),Element::List(vec![Element::Integer(1),Element::Integer(9)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(1),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(9),Element::Integer(4),Element::Integer(1)]),Element::Integer(2),Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(9),Element::Integer(2),Element::Integer(7)])])]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::Integer(10),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(9)]),Element::Integer(2),Element::Integer(5)]),Element::Integer(7),Element::List(vec![Element::List(vec![]),Element::Integer(2)])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4),Element::List(vec![Element::Integer(7),Element::Integer(0),Element::Integer(1)]),Element::Integer(5)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(6),Element::Integer(6),Element::Integer(7)]),Element::List(vec![Element::Integer(2)]),Element::Integer(9)]),Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6)]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(7)])]),Element::Integer(7)]),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(2),Element::Integer(1)]),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(3),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(2),Element::Integer(9)])])]),Element::List(vec![Element::Integer(0)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(10)]),Element::List(vec![Element::Integer(3),Element::Integer(7),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(2),Element::Integer(5),Element::Integer(7)]),Element::Integer(0),Element::Integer(3)])]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(9),Element::Integer(7)]),Element::Integer(3),Element::Integer(6),Element::Integer(2),Element::List(vec![])]),Element::List(vec![Element::Integer(7),Element::Integer(10),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(3),Element::Integer(8)]),Element::List(vec![])]),Element::Integer(1),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(0)])])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::Integer(8),Element::Integer(1),Element::Integer(9),Element::List(vec![Element::Integer(7),Element::Integer(2),Element::Integer(1),Element::Integer(0),Element::Integer(7)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(5)]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(3),Element::Integer(6)]),Element::Integer(6)]),Element::Integer(5),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(10),Element::Integer(10)]),Element::Integer(8)]),Element::Integer(8),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(1),Element::Integer(9),Element::Integer(9)]),Element::List(vec![]),Element::Integer(2)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)])]),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(2)]),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(1),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(7),Element::Integer(8)]),Element::Integer(6),Element::Integer(3)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(2),Element::List(vec![Element::Integer(5),Element::Integer(8)])]),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(9),Element::Integer(8)]),Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(9),Element::Integer(9)])]),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4)]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(3)]),Element::List(vec![Element::Integer(0)]),Element::Integer(1),Element::Integer(3),Element::Integer(7)])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(6),Element::Integer(2)]),Element::List(vec![])]),Element::Integer(0)]),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::List(vec![]),Element::Integer(5),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(1)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6),Element::List(vec![]),Element::Integer(1),Element::Integer(9)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(1)]),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(0),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(6),Element::Integer(9)]),Element::Integer(6),Element::List(vec![Element::Integer(8),Element::Integer(2)])])]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::Integer(10),Element::List(vec![])])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(10),Element::Integer(6)]),Element::List(vec![Element::Integer(5),Element::Integer(1)]),Element::Integer(7)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(8)]),Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(9),Element::Integer(1),Element::Integer(3),Element::Integer(3)])]),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(4),Element::Integer(8),Element::Integer(2)]),Element::List(vec![Element::Integer(4)])])]),Element::List(vec![]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(9)]),Element::List(vec![Element::Integer(3),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(6)])]),Element::Integer(7),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::Integer(8),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(8),Element::Integer(8),Element::Integer(10),Element::Integer(10)]),Element::List(vec![Element::Integer(10)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(6),Element::Integer(1),Element::Integer(7)]),Element::Integer(4),Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(6)])]),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(10),Element::Integer(9),Element::Integer(1)]),Element::Integer(8)]),Element::List(vec![])]),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(5),Element::Integer(0)]),Element::Integer(4),Element::Integer(10),Element::List(vec![Element::Integer(3),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(8)])])]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::List(vec![]),Element::Integer(0),Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![])]),Element::Integer(7),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(4),Element::Integer(5)]),Element::Integer(9)]),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(9),Element::Integer(2)])]),Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(8),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(2),Element::Integer(0),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(3),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(9)])]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(2)]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(3),Element::Integer(8)]),Element::Integer(8),Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(8),Element::Integer(8),Element::Integer(7)]),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(0)]),Element::List(vec![])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(3),Element::Integer(3),Element::Integer(0),Element::Integer(0)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(0),Element::Integer(2)]),Element::List(vec![Element::Integer(6),Element::Integer(2),Element::Integer(9)]),Element::List(vec![Element::Integer(2),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(3)]),Element::List(vec![])]),Element::Integer(10),Element::List(vec![Element::Integer(6),Element::List(vec![]),Element::Integer(10),Element::List(vec![Element::Integer(9),Element::Integer(6)])])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(0),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(0)]),Element::Integer(1),Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(2),Element::Integer(7),Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(9)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(9)]),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(0),Element::Integer(1)]),Element::List(vec![Element::Integer(0),Element::Integer(5),Element::Integer(10),Element::Integer(1)])]),Element::List(vec![]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(4),Element::List(vec![]),Element::List(vec![])]),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(4),Element::Integer(3),Element::Integer(4)]),Element::Integer(3)])]),Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::Integer(9),Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(7),Element::Integer(8)]),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(3),Element::Integer(3)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(10),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::List(vec![])]),Element::Integer(3),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(6)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(10),Element::Integer(2)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(0),Element::Integer(6),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(0),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(8),Element::Integer(10)]),Element::Integer(10),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(3)])]),Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(1),Element::Integer(2)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(0),Element::Integer(5),Element::Integer(7)])]),Element::Integer(8),Element::List(vec![]),Element::Integer(6)]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::List(vec![]),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(5),Element::Integer(7),Element::Integer(9),Element::Integer(9)]),Element::List(vec![Element::Integer(0),Element::Integer(3)]),Element::Integer(9),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(1),Element::List(vec![Element::Integer(1)])]),Element::Integer(8)]),Element::List(vec![Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(9)]),Element::Integer(2),Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(0),Element::Integer(1),Element::Integer(10)]),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(4)])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(9),Element::Integer(2)])]),Element::Integer(5),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(8),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(2),Element::Integer(4)]),Element::Integer(5)])]),Element::List(vec![Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(10)])]),Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(9)]),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(3),Element::Integer(3)])]),Element::List(vec![Element::Integer(4),Element::Integer(1),Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(4),Element::Integer(3),Element::Integer(8)]),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(10),Element::Integer(3)]),Element::Integer(6)])]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(3)])])]),Element::List(vec![Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(2)]),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(2),Element::Integer(7)])]),Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(9),Element::Integer(4)]),
-        Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(9),Element::Integer(4),Element::Integer(1)]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7)])]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(9),Element::Integer(3)]),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(10)])]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5),Element::Integer(0),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(6)])])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(4),Element::List(vec![Element::Integer(2)]),Element::Integer(0)]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(3),Element::Integer(8)]),Element::Integer(1)]),Element::Integer(3)]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::List(vec![]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3)])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8)]),Element::Integer(4),Element::Integer(10)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::Integer(3)]),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(10)])])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(7),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(3),Element::Integer(9)])]),Element::Integer(8),Element::List(vec![]),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(10),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(1),Element::Integer(7),Element::Integer(6),Element::Integer(0),Element::Integer(4)]),Element::Integer(9),Element::List(vec![Element::Integer(7),Element::Integer(2)]),Element::List(vec![Element::Integer(8),Element::Integer(5),Element::Integer(4)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::List(vec![Element::List(vec![]),Element::Integer(9),Element::Integer(3),Element::Integer(3),Element::List(vec![Element::Integer(10),Element::Integer(6)])]),Element::Integer(7)]),Element::List(vec![Element::Integer(6),Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::Integer(3),Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(9),Element::Integer(10),Element::Integer(5)]),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(8),Element::List(vec![Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(6),Element::Integer(10),Element::Integer(9)]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::Integer(3),Element::Integer(9),Element::Integer(6)])]),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(1)]),Element::Integer(3)]),Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(0),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::List(vec![]),Element::Integer(7),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(7),Element::Integer(5)]),Element::Integer(8)]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(4)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(8)]),Element::Integer(2),Element::List(vec![Element::Integer(3),Element::Integer(0),Element::Integer(1)]),Element::List(vec![Element::Integer(5),Element::Integer(3),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(7)]),Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(3)])]),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(8),Element::Integer(8),Element::Integer(6)]),Element::Integer(4)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(8),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::Integer(1),Element::Integer(9)]),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(7)]),Element::Integer(10),Element::Integer(1),Element::Integer(2),Element::Integer(3)]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(5),Element::Integer(4)]),Element::Integer(4),Element::List(vec![])]),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(0)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(5),Element::Integer(7)]),Element::Integer(5),Element::Integer(5)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(1),Element::Integer(6),Element::Integer(6)]),Element::Integer(4),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(0)]),Element::Integer(7),Element::Integer(1),Element::Integer(10)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(6),Element::Integer(6)]),Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(7)]),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(0)])]),Element::List(vec![])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(5)]),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(0),Element::Integer(7),Element::Integer(9)]),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(2),Element::Integer(10),Element::Integer(8)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(6),Element::Integer(1)])]),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(9)]),Element::Integer(5),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(9),Element::Integer(3)]),Element::Integer(8),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(9),Element::Integer(0)]),Element::Integer(3),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(8),Element::Integer(1),Element::Integer(9)])]),Element::Integer(4),Element::List(vec![])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6)])]),Element::List(vec![Element::Integer(9),Element::Integer(4),Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(4)])])]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8)])]),Element::Integer(0)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(6)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(1),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(0),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(10),Element::Integer(3)])]),Element::Integer(10)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(8)])])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(3)]),Element::List(vec![Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(3)]),Element::Integer(7),Element::Integer(4),Element::Integer(3),Element::Integer(2)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(9),Element::Integer(4)]),Element::Integer(7)])]),Element::List(vec![]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(10),Element::Integer(8),Element::Integer(1)]),Element::List(vec![Element::Integer(3),Element::Integer(9),Element::Integer(1)]),Element::List(vec![]),Element::Integer(4)])]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(8)]),Element::List(vec![Element::Integer(6)]),Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::Integer(2),Element::Integer(7),Element::Integer(2)]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(5),Element::Integer(1),Element::Integer(8)])]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(7),Element::Integer(2),Element::Integer(4)]),Element::Integer(6)]),Element::List(vec![Element::Integer(7)]),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(6),Element::Integer(9),Element::Integer(0)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(8),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(3),Element::Integer(8),Element::Integer(3)]),Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(2)]),Element::List(vec![Element::Integer(7),Element::Integer(6)]),Element::Integer(6)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(9),Element::Integer(1),Element::Integer(4)]),Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(1)]),Element::Integer(6),Element::Integer(8),Element::Integer(6)]),Element::Integer(4),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(8)]),Element::Integer(1),Element::Integer(3)]),Element::Integer(4),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(1)]),Element::List(vec![Element::Integer(6),Element::Integer(7),Element::Integer(5),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(0),Element::Integer(3),Element::Integer(5)]),Element::Integer(9),Element::Integer(0),Element::List(vec![Element::Integer(9)]),Element::Integer(6)])]),Element::List(vec![Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::Integer(4),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(2)]),Element::List(vec![Element::Integer(2)])]),
-        ),(
-        Element::List(vec![]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(4),Element::Integer(3)]),Element::List(vec![])]),Element::Integer(10),Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(0),Element::Integer(3),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(9),Element::Integer(8),Element::Integer(7)])]),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(5),Element::Integer(10)]),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(10),Element::Integer(9)]),Element::List(vec![Element::Integer(9),Element::Integer(2),Element::Integer(6)]),Element::Integer(7),Element::List(vec![]),Element::Integer(4)])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(3),Element::List(vec![Element::Integer(7),Element::Integer(2),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(6),Element::Integer(7),Element::Integer(6)])]),Element::List(vec![Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(0),Element::Integer(1)])])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(4),Element::Integer(3),Element::Integer(4)]),Element::Integer(1),Element::Integer(1),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(3)])]),Element::List(vec![Element::Integer(2),Element::Integer(5)])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(3),Element::Integer(3),Element::Integer(9)]),Element::Integer(4),Element::List(vec![Element::Integer(0),Element::Integer(5)])]),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2)]),Element::Integer(6),Element::List(vec![Element::Integer(1),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(6)]),Element::Integer(8),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(1),Element::Integer(4)]),Element::List(vec![Element::Integer(0),Element::Integer(7)])]),Element::Integer(8),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(1),Element::Integer(2)]),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(5),Element::Integer(2)])]),Element::Integer(1),Element::Integer(7),Element::Integer(7),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(5)])])]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(1),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9)])]),Element::Integer(2),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(5),Element::Integer(10),Element::Integer(9)]),Element::Integer(8),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(0),Element::Integer(4),Element::Integer(10)])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(10)]),Element::List(vec![Element::Integer(9),Element::Integer(10)])])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(8),Element::Integer(9),Element::Integer(10)]),Element::List(vec![])]),Element::Integer(7),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(6),Element::Integer(9),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(5)])]),Element::Integer(6)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(0)]),Element::List(vec![Element::Integer(1),Element::Integer(2)])])]),Element::List(vec![Element::Integer(4)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(7)])]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(6)])]),Element::Integer(6),Element::Integer(5),Element::List(vec![Element::Integer(9)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)])]),Element::Integer(2),Element::List(vec![Element::List(vec![]),Element::Integer(2)]),Element::Integer(4)]),Element::List(vec![Element::Integer(4),Element::Integer(9)]),Element::List(vec![Element::Integer(5),Element::Integer(4),Element::Integer(0),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(10)])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(3),Element::Integer(10)]),Element::Integer(9),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(6)]),Element::Integer(6)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8)]),Element::List(vec![]),Element::Integer(3),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(2),Element::List(vec![Element::Integer(1),Element::Integer(9),Element::Integer(5)]),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::List(vec![]),Element::List(vec![])]),Element::Integer(8),Element::Integer(6),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(8),Element::Integer(5),Element::Integer(4),Element::Integer(0),Element::Integer(9)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(8),Element::Integer(2),Element::Integer(1),Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(1)]),Element::Integer(10)]),Element::Integer(1),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::Integer(4),Element::Integer(5)]),Element::Integer(4),Element::List(vec![Element::Integer(8),Element::List(vec![]),Element::Integer(4),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(4),Element::Integer(2)]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(2),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(5),Element::Integer(7)]),Element::Integer(10)]),Element::List(vec![Element::Integer(3),Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(8),Element::Integer(1),Element::Integer(8)]),Element::List(vec![Element::Integer(10),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::Integer(8),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(8)]),Element::Integer(2)]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(4),Element::Integer(9)]),Element::Integer(1),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(8),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(7),Element::Integer(6),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(6)]),Element::Integer(3),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(4),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(5)]),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(4),Element::Integer(3),Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::Integer(5)]),Element::List(vec![Element::Integer(6),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(4)]),Element::Integer(10),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(4),Element::Integer(7)]),Element::List(vec![Element::Integer(5)])])]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(0)]),Element::Integer(3),Element::List(vec![Element::Integer(5),Element::Integer(4),Element::Integer(8),Element::Integer(0),Element::Integer(8)])]),Element::Integer(9),Element::Integer(1),Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(8)]),Element::List(vec![Element::Integer(3),Element::Integer(9)]),Element::Integer(8),Element::Integer(6),Element::Integer(6)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(10),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(3)])]),Element::Integer(0)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(1),Element::Integer(9)]),Element::Integer(3)]),Element::Integer(3),Element::Integer(7),Element::Integer(7)]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(3)]),Element::Integer(6),Element::Integer(2),Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(10),Element::Integer(7)]),Element::List(vec![Element::Integer(9)]),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(3),Element::List(vec![Element::Integer(4),Element::Integer(5),Element::Integer(1),Element::Integer(3),Element::Integer(9)]),Element::Integer(10)]),Element::Integer(3),Element::Integer(2),Element::List(vec![Element::List(vec![]),Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(3)])]),Element::List(vec![]),Element::Integer(2)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(7),Element::Integer(6),Element::Integer(3),Element::Integer(5)])]),Element::Integer(3),Element::List(vec![Element::Integer(2)])]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(10),Element::Integer(0),Element::Integer(10)]),Element::Integer(1),Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(3),Element::Integer(5),Element::Integer(3)]),Element::Integer(2),Element::Integer(5),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::Integer(4),Element::Integer(9)]),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(8),Element::Integer(2),Element::Integer(1)]),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(1)]),Element::List(vec![Element::Integer(5),Element::Integer(9)]),Element::Integer(3),Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(4)]),Element::List(vec![]),Element::Integer(6),Element::Integer(7),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(6),Element::Integer(1),Element::Integer(3)])]),Element::Integer(6),Element::Integer(2)]),Element::List(vec![Element::Integer(0),Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(5),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::Integer(3),Element::Integer(4)]),Element::Integer(10),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(8),Element::Integer(6)])]),Element::Integer(2),Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(7)])]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(8),Element::Integer(5),Element::Integer(3)]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(3)]),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(8)]),Element::Integer(8)])]),Element::List(vec![Element::Integer(4),Element::Integer(9),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(2)]),Element::Integer(4),Element::List(vec![Element::Integer(9),Element::Integer(6)])])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(5),Element::Integer(4),Element::Integer(7)])]),Element::Integer(4)]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(3)])]),Element::List(vec![Element::Integer(8)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(1),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::List(vec![Element::Integer(2),Element::Integer(4)]),Element::Integer(8),Element::List(vec![])]),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(1),Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(8)]),Element::Integer(3)])]),Element::List(vec![Element::Integer(8),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(5),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::Integer(4),Element::Integer(8),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(5),Element::List(vec![]),Element::Integer(3),Element::List(vec![Element::Integer(7),Element::Integer(0),Element::Integer(4),Element::Integer(9),Element::Integer(0)])]),Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(5)])]),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(9),Element::Integer(5)]),Element::Integer(0),Element::Integer(10)]),Element::Integer(0)]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(9)])]),Element::List(vec![Element::Integer(10),Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(0),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(1),Element::Integer(9)]),Element::List(vec![]),Element::Integer(1),Element::Integer(1)])]),Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(6)]),Element::Integer(9)]),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(5)]),Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(4)]),Element::List(vec![]),Element::Integer(9)]),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(1)]),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(9),Element::Integer(0),Element::Integer(6)]),Element::Integer(2)])]),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(7),Element::List(vec![Element::Integer(9),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(1),Element::Integer(4),Element::Integer(2)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(2)]),Element::Integer(9)])]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4)]),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(10),Element::Integer(7)]),Element::Integer(6),Element::Integer(2),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(3)])]),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(2),Element::Integer(4),Element::Integer(3),Element::Integer(4)]),Element::Integer(5),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(2)]),Element::Integer(7)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(7),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7)]),Element::Integer(0),Element::Integer(4),Element::Integer(2)]),Element::Integer(3)]),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(5)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(0)])]),Element::List(vec![]),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1)])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(5)]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(5)]),Element::Integer(4),Element::List(vec![]),Element::List(vec![Element::Integer(4)]),Element::Integer(2)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(9),Element::List(vec![Element::Integer(7)])]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(9),Element::Integer(1)]),Element::Integer(8),Element::Integer(5)]),Element::List(vec![]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(8),Element::Integer(8)]),Element::Integer(1)]),Element::List(vec![]),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(2)]),Element::Integer(5),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(7),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(10),Element::Integer(8)])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(9)]),Element::Integer(1)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(9),Element::Integer(0)]),Element::Integer(10),Element::List(vec![Element::Integer(9)]),Element::Integer(2),Element::Integer(3)]),Element::List(vec![Element::Integer(6),Element::List(vec![]),Element::List(vec![Element::Integer(2)])]),Element::List(vec![])]),Element::List(vec![Element::Integer(8),Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(0),Element::Integer(5)])])]),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(8),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::Integer(2),Element::Integer(10),Element::Integer(6)]),Element::List(vec![Element::Integer(1)]),Element::List(vec![]),Element::Integer(5)])]),Element::List(vec![Element::Integer(5),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(10),Element::Integer(10)]),Element::List(vec![]),Element::Integer(5)]),Element::Integer(6)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(6)])]),Element::List(vec![Element::Integer(9),Element::Integer(10),Element::List(vec![Element::Integer(8)])]),Element::List(vec![Element::Integer(4),Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(6),Element::Integer(1),Element::Integer(10)]),Element::Integer(2)])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5),Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(6)]),Element::List(vec![Element::Integer(8)]),Element::Integer(4)])]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::Integer(4)]),Element::List(vec![Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(8),Element::Integer(3),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(6),Element::Integer(8)]),Element::Integer(7),Element::Integer(2)])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(6),Element::Integer(4),Element::Integer(3)]),Element::List(vec![]),Element::List(vec![])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(1),Element::Integer(10),Element::Integer(10)]),Element::Integer(5)]),Element::Integer(9),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(2),Element::Integer(8),Element::Integer(9)]),Element::Integer(2),Element::Integer(7),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(6),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(9),Element::Integer(10)]),Element::List(vec![Element::Integer(4)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(3),Element::Integer(4)]),Element::Integer(6),Element::List(vec![Element::Integer(2),Element::Integer(10),Element::Integer(1)])]),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::List(vec![Element::Integer(8)]),Element::Integer(4),Element::Integer(5)]),Element::Integer(4),Element::Integer(5)]),Element::List(vec![Element::Integer(2)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(4),Element::Integer(0),Element::Integer(10)]),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![]),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(9)])]),Element::Integer(6)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(2)])]),Element::List(vec![Element::Integer(6)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(7)]),Element::Integer(9),Element::Integer(7),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(7)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(1)])]),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(5),Element::Integer(4),Element::Integer(2)]),Element::Integer(8),Element::Integer(9),Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(0),Element::Integer(10)]),Element::Integer(0),Element::Integer(1)]),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(4),Element::Integer(8),Element::Integer(2)]),Element::List(vec![Element::Integer(10),Element::Integer(8),Element::List(vec![Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(3),Element::Integer(5),Element::Integer(10)])])]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![])]),Element::Integer(9),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(3),Element::Integer(7),Element::Integer(2)])]),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8)]),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(0)]),Element::Integer(8),Element::Integer(3)]),Element::Integer(8),Element::Integer(6),Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(2)]),Element::Integer(7),Element::Integer(2),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(4),Element::Integer(2)]),Element::Integer(9)]),Element::Integer(2),Element::Integer(9)]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(7)]),Element::Integer(0)]),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(2),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(1)]),Element::Integer(5)])]),Element::List(vec![Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(0),Element::Integer(2),Element::Integer(3)]),Element::List(vec![Element::Integer(9)]),Element::Integer(9)]),Element::Integer(4)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(1),Element::Integer(4)]),Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(6),Element::Integer(6)]),Element::Integer(0)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(5),Element::Integer(0),Element::Integer(4),Element::Integer(8)]),Element::Integer(3),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(0),Element::Integer(2),Element::Integer(6)]),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(9),Element::Integer(1),Element::Integer(5)])]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(10)])]),Element::List(vec![Element::Integer(9)]),Element::Integer(3),Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(5)]),Element::Integer(1),Element::Integer(8),Element::List(vec![Element::Integer(9)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(2)]),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(10),Element::Integer(2)]),Element::Integer(0),Element::List(vec![Element::Integer(8),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(0),Element::Integer(4)]),Element::List(vec![Element::Integer(6)]),Element::Integer(9),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(4),Element::Integer(0)])]),Element::Integer(0)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(2),Element::Integer(0)]),Element::Integer(4),Element::Integer(6),Element::Integer(1)]),Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(9),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(0),Element::Integer(5)]),Element::Integer(6)])]),Element::List(vec![Element::Integer(10),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(9),Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(1)]),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(6)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::Integer(3),Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(6)]),Element::Integer(2),Element::Integer(4),Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(8),Element::Integer(7),Element::Integer(2)])]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(5),Element::Integer(7)]),Element::List(vec![Element::Integer(6),Element::Integer(3)])]),Element::Integer(1)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(1),Element::Integer(1)]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(7),Element::Integer(8)]),Element::Integer(10),Element::Integer(6),Element::Integer(0)]),Element::Integer(0),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(3),Element::Integer(9)]),Element::List(vec![Element::Integer(0),Element::Integer(8),Element::Integer(5),Element::Integer(8),Element::Integer(8)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(6),Element::Integer(1)])]),Element::List(vec![]),Element::Integer(7),Element::Integer(10)]),Element::List(vec![Element::Integer(3),Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(4),Element::Integer(7),Element::Integer(4)]),Element::Integer(0),Element::List(vec![])]),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(3),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(6)])])]),Element::List(vec![Element::Integer(1),Element::List(vec![]),Element::Integer(7),Element::Integer(10),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(1),Element::Integer(1),Element::Integer(7),Element::Integer(7),Element::Integer(7)]),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(4)])])]),Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::Integer(6),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(5),Element::Integer(3)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(8),Element::Integer(2),Element::Integer(7),Element::Integer(10)]),Element::Integer(9),Element::List(vec![Element::Integer(8)]),Element::Integer(1)]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(9)]),Element::Integer(1),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(0),Element::Integer(4)]),Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::Integer(7)]),Element::Integer(7),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(6),Element::Integer(6)]),Element::Integer(8)]),Element::List(vec![Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(2)])]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(9),Element::Integer(5)]),Element::Integer(9)]),Element::Integer(6)]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(6)])]),Element::List(vec![Element::Integer(4),Element::Integer(5),Element::Integer(5)]),Element::Integer(0),Element::Integer(8)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(4),Element::Integer(1),Element::Integer(3)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(8)]),Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(8),Element::Integer(2)]),Element::Integer(5),Element::Integer(4)])]),Element::List(vec![Element::List(vec![]),Element::Integer(4)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(2)]),Element::Integer(3),Element::Integer(5)])]),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(6)])])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4)]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(10)]),Element::Integer(3)]),Element::List(vec![]),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::List(vec![])])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(2)]),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(4),Element::Integer(5),Element::Integer(4)]),Element::List(vec![Element::Integer(0),Element::Integer(2)])]),Element::Integer(10),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(9),Element::Integer(8)]),Element::Integer(9)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(8),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::Integer(7),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::Integer(5),Element::Integer(5)])])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(3),Element::List(vec![Element::Integer(9),Element::Integer(2),Element::Integer(0),Element::Integer(2),Element::Integer(6)])]),Element::Integer(8),Element::Integer(3),Element::List(vec![Element::Integer(2),Element::Integer(2)])]),Element::List(vec![Element::Integer(9),Element::List(vec![]),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(0),Element::Integer(7),Element::Integer(0)]),Element::List(vec![Element::Integer(0)]),Element::Integer(8),Element::Integer(7),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(8),Element::Integer(0),Element::Integer(6)])]),Element::Integer(6)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(4)]),Element::Integer(8)]),Element::Integer(4)]),Element::List(vec![Element::List(vec![]),Element::Integer(2),Element::Integer(6),Element::Integer(8),Element::Integer(7)]),Element::List(vec![Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(6),Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(9),Element::Integer(7),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(8),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(1)]),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4),Element::Integer(3)]),Element::List(vec![Element::Integer(2),Element::Integer(5)]),Element::List(vec![Element::Integer(5),Element::Integer(9),Element::Integer(3)])]),Element::Integer(8),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(9),Element::List(vec![]),Element::Integer(10)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(2),Element::Integer(10)])]),Element::Integer(8),Element::Integer(8),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(5),Element::Integer(0),Element::Integer(2)])])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(10),Element::List(vec![Element::Integer(4),Element::Integer(5),Element::Integer(2),Element::Integer(4)]),Element::List(vec![Element::Integer(3)])]),Element::Integer(10),Element::List(vec![Element::List(vec![])]),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(10),Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(10),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(5)]),Element::Integer(9),Element::Integer(6)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(10),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(0)]),Element::Integer(2),Element::List(vec![Element::Integer(5),Element::Integer(5)])]),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(2),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(7),Element::Integer(7)])])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(7),Element::Integer(2),Element::Integer(8),Element::Integer(8)])]),Element::List(vec![]),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(5),Element::Integer(8),Element::Integer(9)]),Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(6),Element::Integer(7),Element::Integer(0)]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(8),Element::Integer(10)])]),Element::List(vec![Element::Integer(10)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(8)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(4),Element::Integer(1)]),Element::Integer(2)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(5)]),Element::Integer(0),Element::Integer(8),Element::List(vec![Element::Integer(3),Element::Integer(7),Element::Integer(10),Element::Integer(6),Element::Integer(7)])]),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::List(vec![Element::Integer(1)]),Element::Integer(0),Element::Integer(5)]),Element::List(vec![Element::Integer(7),Element::Integer(0),Element::Integer(5)])]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10),Element::List(vec![])]),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(8)]),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(4),Element::Integer(4)]),Element::Integer(5)]),Element::List(vec![Element::Integer(4)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(7)])])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(2),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(0),Element::Integer(10)]),Element::List(vec![]),Element::Integer(1)]),Element::List(vec![Element::Integer(8),Element::Integer(10),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(2),Element::Integer(4)])]),Element::List(vec![Element::Integer(8)])]),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(7)]),Element::Integer(5),Element::Integer(10),Element::Integer(5)])]),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(6),Element::Integer(0),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(0),Element::Integer(2)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(10),Element::Integer(1),Element::Integer(9)]),Element::List(vec![Element::Integer(1)]),Element::Integer(3)]),Element::Integer(9),Element::List(vec![Element::Integer(5),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(6)]),Element::Integer(6),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(3)]),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(3),Element::Integer(8),Element::Integer(9)]),Element::Integer(9),Element::List(vec![])]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(4),Element::Integer(1),Element::List(vec![Element::Integer(7),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::Integer(8),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(0),Element::Integer(0),Element::Integer(1)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(5)])]),Element::List(vec![Element::Integer(0),Element::Integer(9)]),Element::List(vec![Element::List(vec![])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(9),Element::Integer(3),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::List(vec![])]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::Integer(1)]),Element::Integer(9),Element::Integer(0),Element::Integer(10),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(10),Element::Integer(1),Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(5),Element::Integer(2),Element::Integer(8),Element::Integer(6)])]),Element::Integer(2),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(6),Element::Integer(8),Element::Integer(9)]),Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(5)]),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::Integer(1),Element::Integer(7),Element::Integer(10)]),Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(3),Element::Integer(8),Element::Integer(3)]),Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::Integer(10),Element::Integer(8),Element::Integer(6),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::Integer(6),Element::Integer(6)])]),Element::Integer(8),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(5),Element::Integer(10),Element::Integer(0)]),Element::Integer(10)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(7),Element::Integer(9),Element::Integer(3)]),Element::List(vec![]),Element::Integer(0),Element::Integer(5),Element::List(vec![Element::Integer(8),Element::Integer(4),Element::Integer(4),Element::Integer(2),Element::Integer(6)])]),Element::Integer(3),Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(9),Element::Integer(7),Element::Integer(9),Element::Integer(2)])]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(4),Element::Integer(0),Element::Integer(0)]),Element::List(vec![Element::Integer(4),Element::Integer(0),Element::Integer(4),Element::Integer(4)]),Element::List(vec![Element::Integer(3),Element::Integer(4)])]),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::List(vec![]),Element::Integer(7),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(5),Element::Integer(4),Element::Integer(0)])]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(1),Element::Integer(2),Element::Integer(0)]),Element::List(vec![Element::Integer(5),Element::Integer(0),Element::Integer(10),Element::Integer(4)]),Element::List(vec![]),Element::Integer(3)]),Element::Integer(9)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::Integer(0)])]),Element::List(vec![Element::Integer(10),Element::Integer(8)])]),Element::List(vec![]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(3),Element::Integer(5),Element::Integer(5)]),Element::Integer(5),Element::Integer(9)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(8),Element::Integer(9)]),Element::Integer(7),Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(4),Element::Integer(0)]),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(2),Element::Integer(1),Element::Integer(3),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(0)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(1)]),Element::Integer(10),Element::Integer(0),Element::Integer(4)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(5)])]),Element::List(vec![Element::Integer(4),Element::Integer(10),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(8),Element::Integer(3),Element::Integer(1)])]),Element::Integer(6)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(3)])]),Element::Integer(6),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(6)]),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(3),Element::Integer(0)]),Element::List(vec![Element::Integer(0),Element::Integer(9)]),Element::Integer(4),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(10)]),Element::Integer(1)])]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::List(vec![Element::Integer(4),Element::Integer(8)])]),Element::Integer(10),Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(9),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(0),Element::Integer(6),Element::Integer(8)]),Element::Integer(9)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4),Element::Integer(5),Element::Integer(9),Element::Integer(6)])]),Element::Integer(0),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(2),Element::Integer(3)]),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(9)]),Element::Integer(6),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(5)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(2),Element::Integer(6)]),Element::Integer(7),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(2)])]),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::Integer(8)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(4)]),Element::Integer(10),Element::List(vec![]),Element::List(vec![]),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::Integer(10)])]),Element::Integer(10)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(4),Element::Integer(4)]),Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(0),Element::Integer(2),Element::Integer(6)]),Element::Integer(10),Element::Integer(7),Element::List(vec![]),Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(7),Element::Integer(1)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(7),Element::Integer(6),Element::Integer(1),Element::Integer(5),Element::Integer(5)]),Element::Integer(4),Element::Integer(4),Element::Integer(0)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::List(vec![Element::Integer(8),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(6),Element::Integer(4),Element::Integer(9)]),Element::List(vec![Element::Integer(10),Element::Integer(6)])]),Element::Integer(1),Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(1),Element::Integer(10),Element::Integer(7)]),Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(0),Element::Integer(9)])]),Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::Integer(2)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(0)]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(1)])]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(1)])])]),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(7),Element::Integer(8)]),Element::List(vec![Element::Integer(1),Element::Integer(10)])]),Element::Integer(7),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(9)]),Element::List(vec![Element::Integer(9),Element::Integer(6)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::List(vec![Element::Integer(9),Element::Integer(10),Element::Integer(4),Element::Integer(2),Element::Integer(1)]),Element::Integer(4),Element::Integer(7)]),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(9)]),Element::Integer(8)]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(1),Element::Integer(2),Element::Integer(3)]),Element::Integer(9),Element::Integer(10),Element::List(vec![Element::Integer(4),Element::Integer(8)])]),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(0),Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(9),Element::Integer(1),Element::Integer(5)]),Element::Integer(2),Element::Integer(6)]),Element::Integer(10),Element::Integer(5),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(5),Element::Integer(10)])])]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(7),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(3),Element::Integer(8)])])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::Integer(0),Element::Integer(3),Element::Integer(6)]),Element::Integer(10),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(1),Element::Integer(6)])])]),Element::List(vec![Element::Integer(0),Element::Integer(5),Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::List(vec![Element::Integer(8)]),Element::Integer(1)]),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(7),Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(6)])]),Element::Integer(7),Element::Integer(5)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![]),Element::Integer(2)]),Element::List(vec![]),Element::Integer(1),Element::List(vec![Element::Integer(1),Element::Integer(9),Element::List(vec![]),Element::Integer(3)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(10),Element::Integer(10),Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::Integer(0)]),Element::Integer(7)]),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(4),Element::Integer(2),Element::Integer(10)]),Element::Integer(8),Element::List(vec![Element::Integer(8),Element::Integer(1)]),Element::List(vec![Element::Integer(10),Element::Integer(8)])]),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(1),Element::List(vec![]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(5)]),Element::Integer(1)]),Element::Integer(1)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(5),Element::Integer(2)]),Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(4),Element::Integer(4)]),Element::Integer(2),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(0),Element::Integer(10)]),Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(5),Element::Integer(1)]),Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(1),Element::Integer(5),Element::Integer(1)])])]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::Integer(6)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![])]),Element::Integer(4),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::Integer(3),Element::Integer(8),Element::Integer(2),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(0),Element::Integer(0)]),Element::Integer(2),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(2)]),Element::Integer(1)]),Element::Integer(9)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(0),Element::Integer(7)]),Element::Integer(9),Element::List(vec![]),Element::Integer(9)]),Element::Integer(5)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(9),Element::Integer(2),Element::Integer(6)]),Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(5),Element::Integer(5),Element::Integer(0)])]),Element::Integer(1),Element::List(vec![]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(5)]),Element::Integer(7),Element::Integer(7),Element::Integer(0)]),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(5)]),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0)])]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(4),Element::Integer(7),Element::Integer(5)]),Element::List(vec![])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(7),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::Integer(2),Element::Integer(2),Element::Integer(2),Element::Integer(0)]),Element::List(vec![]),Element::List(vec![Element::Integer(0)])]),Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(3),Element::Integer(6),Element::Integer(10)]),Element::Integer(7),Element::Integer(8),Element::Integer(9)]),Element::Integer(6),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8)]),Element::Integer(1),Element::Integer(0)]),Element::Integer(7)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(6),Element::Integer(4),Element::Integer(5)]),Element::Integer(3),Element::Integer(1),Element::Integer(3)]),Element::List(vec![Element::Integer(4),Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(1)]),Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(10),Element::Integer(8),Element::Integer(6),Element::Integer(5)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(3),Element::Integer(0),Element::Integer(9),Element::Integer(9)]),Element::List(vec![])]),Element::Integer(4),Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(0),Element::Integer(4)])]),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(10),Element::Integer(3),Element::Integer(9)]),Element::Integer(4),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(1),Element::Integer(8),Element::Integer(0)]),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(10),Element::Integer(7),Element::Integer(2)])])]),Element::List(vec![Element::Integer(6),Element::Integer(3)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(10),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(7)])])]),Element::List(vec![Element::Integer(6),Element::Integer(5),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(10)])]),Element::Integer(1),Element::Integer(1)]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(10),Element::Integer(10)])]),Element::Integer(1),Element::Integer(6)]),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::List(vec![])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![]),Element::Integer(6),Element::Integer(3),Element::Integer(1),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(9),Element::Integer(4)]),Element::Integer(8),Element::Integer(2)]),Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(0),Element::Integer(0),Element::Integer(4)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::Integer(10),Element::Integer(0),Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(6),Element::Integer(8)])])]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(5),Element::List(vec![Element::Integer(0),Element::Integer(3),Element::Integer(3)]),Element::Integer(6)]),Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(2),Element::Integer(7),Element::Integer(1),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::Integer(3),Element::List(vec![])]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(5),Element::Integer(2)]),Element::List(vec![Element::Integer(7)]),Element::Integer(9),Element::Integer(0),Element::Integer(9)]),Element::Integer(5),Element::Integer(10)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(10)]),Element::Integer(9)]),Element::Integer(6),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(10),Element::Integer(9)]),Element::Integer(4),Element::Integer(5),Element::List(vec![])]),Element::Integer(1)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(9),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(1),Element::Integer(2),Element::Integer(7)]),Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(8),Element::Integer(7),Element::Integer(3)])]),Element::Integer(10),Element::Integer(1),Element::Integer(5),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::Integer(4),Element::Integer(4),Element::Integer(4)])]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(8),Element::Integer(6),Element::Integer(10)]),Element::Integer(2),Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(6),Element::Integer(7),Element::Integer(9)]),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(9)]),Element::Integer(7),Element::Integer(1),Element::Integer(8)]),Element::Integer(0)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(9),Element::Integer(3)]),Element::Integer(6),Element::Integer(10),Element::Integer(0),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(4)]),Element::List(vec![]),Element::Integer(6)]),Element::Integer(5),Element::Integer(1),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(9)]),Element::Integer(0),Element::List(vec![Element::Integer(8),Element::Integer(7)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(3),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(8),Element::Integer(5),Element::Integer(8)]),Element::Integer(6)]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::List(vec![Element::Integer(9),Element::Integer(0),Element::Integer(1),Element::Integer(8),Element::Integer(10)]),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::Integer(8)]),Element::Integer(5)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(0)]),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(5),Element::Integer(4),Element::Integer(4)]),Element::Integer(8)]),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(6)])])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(5),Element::Integer(3),Element::Integer(6)])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(5),Element::Integer(9)]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(10),Element::Integer(9),Element::Integer(4),Element::Integer(1),Element::Integer(7)])]),Element::List(vec![Element::Integer(4)])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::Integer(8),Element::List(vec![Element::Integer(2),Element::Integer(8)]),Element::Integer(3),Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(5),Element::Integer(2),Element::Integer(6)])])]),Element::List(vec![]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![]),Element::Integer(4),Element::List(vec![Element::Integer(3),Element::Integer(2),Element::Integer(2),Element::Integer(1),Element::Integer(1)]),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(7),Element::Integer(6),Element::Integer(10),Element::Integer(1)]),Element::Integer(10)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0)]),Element::Integer(4),Element::List(vec![Element::Integer(4),Element::Integer(0)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(1),Element::Integer(9),Element::Integer(4)])])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(4),Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(7),Element::Integer(5),Element::Integer(8),Element::Integer(10)]),Element::List(vec![]),Element::List(vec![Element::Integer(1)])]),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(0)]),Element::Integer(2)]),Element::Integer(4)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::List(vec![Element::Integer(10),Element::Integer(4),Element::Integer(5),Element::Integer(3),Element::Integer(5)])])]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(10)]),Element::Integer(1),Element::List(vec![])]),Element::Integer(10),Element::Integer(1)]),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![])]),Element::Integer(1),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(9)])])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9)])]),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(5),Element::Integer(2),Element::Integer(1),Element::Integer(4)]),Element::List(vec![])]),Element::List(vec![])]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(6),Element::Integer(10)]),Element::Integer(7),Element::Integer(1),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::Integer(0)])]),Element::Integer(10)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(1),Element::Integer(6)]),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(9),Element::Integer(7),Element::Integer(5)]),Element::Integer(7)]),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(5),Element::List(vec![Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8),Element::Integer(0),Element::Integer(4),Element::Integer(0),Element::Integer(4)]),Element::Integer(8)]),Element::Integer(7),Element::List(vec![Element::Integer(4),Element::Integer(10),Element::Integer(4)])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(8),Element::Integer(0),Element::Integer(6)])]),Element::Integer(10),Element::List(vec![Element::Integer(7)]),Element::List(vec![Element::Integer(1),Element::Integer(10)]),Element::Integer(3)]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(4),Element::Integer(4),Element::Integer(6)]),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(6)]),Element::Integer(7),Element::Integer(4),Element::List(vec![])]),Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(2)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(9)]),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(3),Element::Integer(5),Element::Integer(1)]),Element::List(vec![Element::Integer(10),Element::Integer(7)]),Element::Integer(10),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(2)])]),Element::Integer(2)]),Element::List(vec![Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(10),Element::Integer(4),Element::Integer(9),Element::Integer(2)]),Element::List(vec![])]),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(7)]),Element::Integer(8),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(2),Element::Integer(8),Element::Integer(1),Element::Integer(9),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(4)]),Element::Integer(9),Element::Integer(5),Element::List(vec![Element::Integer(6),Element::Integer(0),Element::Integer(2),Element::Integer(7)]),Element::List(vec![])]),Element::Integer(2)]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(0)]),Element::Integer(6)])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::List(vec![Element::Integer(10),Element::Integer(8),Element::Integer(0),Element::Integer(8)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(3),Element::Integer(1),Element::Integer(8)])]),Element::List(vec![Element::List(vec![]),Element::Integer(5),Element::List(vec![Element::List(vec![])]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(3),Element::Integer(6),Element::Integer(9)]),Element::List(vec![Element::Integer(7),Element::Integer(5),Element::Integer(8)])])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(8),Element::Integer(1)])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(3),Element::Integer(7),Element::Integer(0),Element::Integer(5)]),Element::Integer(10)]),Element::Integer(9),Element::List(vec![Element::List(vec![])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(9),Element::Integer(9)]),Element::Integer(8)]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(5)]),Element::List(vec![])])]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(4),Element::Integer(9),Element::Integer(10)]),Element::List(vec![Element::Integer(4),Element::Integer(4),Element::Integer(3),Element::Integer(5),Element::Integer(1)]),Element::Integer(7),Element::List(vec![Element::Integer(0),Element::Integer(9),Element::Integer(7)]),Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(0)])])]),Element::List(vec![Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(2),Element::Integer(10),Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(10),Element::Integer(4),Element::Integer(4),Element::Integer(9)]),Element::List(vec![]),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(1)]),Element::List(vec![Element::Integer(8)]),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![]),Element::Integer(1),Element::Integer(4)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(0)]),Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(8),Element::Integer(10)]),Element::Integer(0),Element::List(vec![Element::Integer(6),Element::Integer(8)])]),Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(4),Element::Integer(7)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(10),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(10)])])]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(7)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(5),Element::Integer(5),Element::Integer(7),Element::Integer(4)]),Element::Integer(1)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(9)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(4),Element::Integer(9),Element::Integer(4),Element::Integer(6),Element::Integer(1)]),Element::Integer(0),Element::List(vec![Element::Integer(1),Element::Integer(3),Element::Integer(4),Element::Integer(3),Element::Integer(0)])]),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::Integer(7)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(1),Element::Integer(0),Element::Integer(10)])]),Element::Integer(3),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(1),Element::Integer(6)])]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(9),Element::Integer(10)])])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(10)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(2)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(7)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(10),Element::Integer(0),Element::List(vec![Element::Integer(9),Element::Integer(4),Element::Integer(4)]),Element::List(vec![Element::Integer(5),Element::Integer(2),Element::Integer(5),Element::Integer(10)]),Element::Integer(4)]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(3),Element::Integer(8),Element::Integer(6)]),Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(6),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(5),Element::Integer(7),Element::Integer(10)]),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(1),Element::Integer(9),Element::Integer(10)])]),Element::List(vec![Element::Integer(1),Element::Integer(10),Element::List(vec![Element::Integer(1),Element::Integer(9),Element::Integer(9),Element::Integer(5)]),Element::List(vec![Element::Integer(8)])])]),Element::List(vec![Element::Integer(10),Element::List(vec![Element::Integer(4),Element::List(vec![]),Element::List(vec![Element::Integer(4),Element::Integer(2)])]),Element::Integer(10),Element::Integer(9)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(10),Element::Integer(3)]),Element::Integer(5),Element::List(vec![Element::Integer(0),Element::Integer(7),Element::Integer(2)])]),Element::List(vec![Element::Integer(4),Element::Integer(4),Element::Integer(2)]),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(10),Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(0)]),Element::Integer(4),Element::Integer(9),Element::List(vec![Element::Integer(4),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(7),Element::Integer(7)])])])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(3),Element::Integer(9),Element::Integer(7)]),Element::List(vec![Element::Integer(5)]),Element::List(vec![Element::Integer(7)]),Element::Integer(10),Element::List(vec![Element::Integer(10),Element::Integer(1),Element::Integer(5),Element::Integer(2),Element::Integer(9)])]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(3),Element::Integer(5)]),Element::Integer(4)]),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::List(vec![Element::Integer(2)])]),Element::Integer(10),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::List(vec![Element::Integer(5),Element::Integer(1),Element::Integer(3)]),Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(3)])]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(8)]),Element::List(vec![Element::Integer(4),Element::List(vec![Element::Integer(9)]),Element::List(vec![]),Element::Integer(3)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(0),Element::Integer(3)]),Element::List(vec![]),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(2),Element::Integer(1),Element::Integer(7)]),Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(2)])]),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(5),Element::Integer(2)]),Element::Integer(4)]),Element::Integer(0)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(4),Element::Integer(4),Element::Integer(5),Element::Integer(10)]),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(6),Element::Integer(3),Element::Integer(2)])]),Element::List(vec![Element::Integer(0)]),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::Integer(8)]),Element::Integer(0),Element::List(vec![Element::Integer(9),Element::Integer(7),Element::Integer(0),Element::Integer(9),Element::Integer(6)])])]),Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(6),Element::Integer(1),Element::List(vec![])]),Element::Integer(9),Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(8),Element::Integer(6),Element::Integer(1),Element::Integer(10)]),Element::List(vec![Element::Integer(2),Element::Integer(1),Element::Integer(3),Element::Integer(3),Element::Integer(7)]),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(0)]),Element::Integer(2)]),Element::Integer(4)]),Element::List(vec![Element::Integer(5)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(3),Element::List(vec![Element::Integer(7),Element::Integer(3),Element::Integer(10)]),Element::List(vec![Element::Integer(6)])])])]),
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(9),Element::Integer(2),Element::List(vec![Element::List(vec![]),Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(6),Element::Integer(5)]),Element::Integer(9)]),Element::List(vec![Element::Integer(0),Element::Integer(2),Element::List(vec![Element::Integer(8),Element::Integer(7),Element::Integer(4),Element::Integer(2),Element::Integer(8)]),Element::Integer(10),Element::List(vec![])])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(2)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(7),Element::Integer(7),Element::Integer(2),Element::List(vec![Element::Integer(7),Element::List(vec![Element::Integer(10),Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::Integer(9),Element::Integer(1)]),Element::List(vec![Element::Integer(7),Element::Integer(8)]),Element::List(vec![])])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(6),Element::List(vec![]),Element::List(vec![Element::Integer(10),Element::Integer(6),Element::Integer(1)]),Element::Integer(6)]),Element::Integer(0),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(10),Element::Integer(9),Element::Integer(5),Element::Integer(7)]),Element::List(vec![Element::Integer(0),Element::Integer(6),Element::Integer(6),Element::Integer(6)])]),Element::Integer(2)]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(5)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(7),Element::Integer(1),Element::Integer(6)]),Element::List(vec![Element::Integer(5),Element::Integer(0),Element::Integer(1)]),Element::List(vec![])]),Element::Integer(3),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(1),Element::Integer(2),Element::Integer(2)]),Element::List(vec![Element::Integer(2),Element::Integer(2)]),Element::List(vec![Element::Integer(0),Element::Integer(0),Element::Integer(8),Element::Integer(4),Element::Integer(3)]),Element::Integer(10),Element::Integer(4)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(6),Element::Integer(6),Element::Integer(6),Element::Integer(8),Element::Integer(10)])])]),Element::List(vec![])]),
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::Integer(5),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(0),Element::Integer(6)])]),Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::List(vec![]),Element::Integer(7),Element::Integer(3),Element::List(vec![])]),Element::List(vec![Element::Integer(2),Element::Integer(6),Element::Integer(4)])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(3)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(1)]),Element::List(vec![Element::Integer(3)])])]),Element::List(vec![Element::Integer(3),Element::Integer(3)]),Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::List(vec![Element::Integer(4),Element::Integer(3),Element::Integer(3)]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(10)]),Element::Integer(5),Element::Integer(10),Element::List(vec![])]),Element::List(vec![Element::List(vec![]),Element::Integer(8)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(2)]),Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(5)]),Element::Integer(1)])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(0),Element::Integer(3),Element::Integer(5)]),Element::Integer(10),Element::List(vec![Element::Integer(2),Element::Integer(5),Element::Integer(4),Element::Integer(4),Element::Integer(2)])]),Element::Integer(1),Element::Integer(10),Element::List(vec![Element::List(vec![Element::Integer(2)])])]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(2),Element::List(vec![Element::Integer(2),Element::Integer(3)]),Element::List(vec![Element::Integer(9),Element::Integer(3),Element::Integer(4)])]),Element::Integer(5),Element::List(vec![Element::Integer(0),Element::List(vec![Element::Integer(10)]),Element::Integer(7),Element::Integer(9),Element::Integer(6)]),Element::List(vec![])]),Element::List(vec![Element::Integer(8),Element::Integer(2),Element::List(vec![Element::Integer(8)])]),Element::List(vec![Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(10),Element::Integer(10),Element::Integer(9)]),Element::List(vec![Element::Integer(6),Element::Integer(4),Element::Integer(3),Element::Integer(8)]),Element::List(vec![Element::Integer(1)])]),Element::Integer(6),Element::Integer(4),Element::Integer(5)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(1),Element::List(vec![]),Element::List(vec![]),Element::List(vec![Element::Integer(5)])]),Element::Integer(6),Element::Integer(7),Element::Integer(9),Element::Integer(1)]),Element::List(vec![Element::Integer(5),Element::Integer(10)]),Element::List(vec![])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(9),Element::List(vec![Element::Integer(9),Element::Integer(1),Element::Integer(10),Element::Integer(9),Element::Integer(8)])]),Element::Integer(9),Element::Integer(6),Element::Integer(0),Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(1)]),Element::Integer(7),Element::Integer(3),Element::List(vec![Element::Integer(4)])])]),Element::List(vec![Element::Integer(1),Element::List(vec![Element::List(vec![Element::Integer(2),Element::Integer(7),Element::Integer(7)]),Element::List(vec![Element::Integer(8),Element::Integer(4)]),Element::List(vec![Element::Integer(7),Element::Integer(9),Element::Integer(3),Element::Integer(3)])]),Element::List(vec![Element::Integer(3),Element::Integer(4)]),Element::Integer(7),Element::Integer(4)]),Element::List(vec![Element::Integer(4),Element::Integer(7),Element::List(vec![]),Element::List(vec![]),Element::Integer(4)])]),
-        Element::List(vec![Element::List(vec![Element::List(vec![]),Element::List(vec![Element::Integer(7)]),Element::Integer(0),Element::Integer(5)]),Element::List(vec![Element::Integer(2)]),Element::List(vec![Element::Integer(4),Element::Integer(6),Element::Integer(4),Element::Integer(6),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![]),Element::Integer(7)]),Element::List(vec![Element::Integer(6)]),Element::List(vec![Element::List(vec![]),Element::Integer(2),Element::List(vec![]),Element::Integer(1),Element::List(vec![Element::Integer(2),Element::Integer(2),Element::Integer(2),Element::Integer(4),Element::Integer(1)])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(4)]),Element::Integer(7),Element::List(vec![Element::Integer(4)]),Element::List(vec![Element::Integer(0),Element::Integer(8),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::Integer(3),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(7)])]),Element::Integer(10)])]),
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(6),Element::List(vec![Element::Integer(5),Element::Integer(8),Element::List(vec![])])])]),
-        ),(
-        Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(10)]),Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(8),Element::Integer(8),Element::Integer(7),Element::Integer(4),Element::Integer(5)])]),Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(9),Element::Integer(0)]),Element::List(vec![Element::Integer(10),Element::Integer(7),Element::Integer(3)]),Element::Integer(4),Element::List(vec![Element::Integer(2),Element::Integer(4),Element::Integer(5)]),Element::List(vec![Element::Integer(5),Element::Integer(7),Element::Integer(10)])]),Element::List(vec![Element::List(vec![Element::Integer(1),Element::Integer(5),Element::Integer(10),Element::Integer(10)]),Element::List(vec![]),Element::List(vec![Element::Integer(3),Element::Integer(3),Element::Integer(9)])])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(0),Element::Integer(1),Element::Integer(9),Element::Integer(5)]),Element::List(vec![Element::Integer(6),Element::Integer(8),Element::Integer(1),Element::Integer(2)]),Element::Integer(8),Element::List(vec![Element::Integer(6),Element::Integer(10),Element::Integer(8)]),Element::Integer(9)]),Element::List(vec![]),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(9),Element::Integer(7)]),Element::Integer(1),Element::Integer(1),Element::Integer(6)]),Element::List(vec![Element::List(vec![])])]),Element::List(vec![Element::Integer(6),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(5),Element::Integer(3),Element::Integer(4),Element::Integer(6)]),Element::List(vec![Element::Integer(8),Element::Integer(6)]),Element::Integer(2),Element::List(vec![Element::Integer(5),Element::Integer(6),Element::Integer(2),Element::Integer(10),Element::Integer(8)]),Element::List(vec![Element::Integer(9),Element::Integer(8),Element::Integer(1),Element::Integer(6),Element::Integer(5)])]),Element::List(vec![]),Element::Integer(10)]),Element::List(vec![Element::List(vec![]),Element::Integer(0),Element::Integer(10),Element::Integer(2),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(8),Element::Integer(8)])])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(7),Element::Integer(10)])]),
-        ),(
-        Element::List(vec![Element::List(vec![]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(8),Element::Integer(4),Element::Integer(0)])]),Element::List(vec![Element::Integer(4),Element::Integer(8),Element::List(vec![Element::Integer(5)]),Element::Integer(1)]),Element::List(vec![Element::List(vec![Element::Integer(1)]),Element::List(vec![Element::Integer(7)]),Element::Integer(5),Element::Integer(5),Element::List(vec![Element::Integer(3)])]),Element::Integer(4)]),Element::List(vec![Element::Integer(5),Element::List(vec![Element::Integer(1),Element::List(vec![Element::Integer(9),Element::Integer(2),Element::Integer(8)]),Element::List(vec![Element::Integer(1),Element::Integer(2),Element::Integer(3),Element::Integer(3),Element::Integer(8)])]),Element::Integer(2),Element::Integer(8),Element::List(vec![Element::Integer(1),Element::Integer(6),Element::Integer(1)])]),Element::List(vec![Element::List(vec![Element::List(vec![Element::Integer(6)])]),Element::Integer(0),Element::Integer(5),Element::Integer(0)]),Element::List(vec![Element::Integer(7),Element::Integer(8),Element::List(vec![Element::Integer(4),Element::Integer(6)])])]),
-        Element::List(vec![Element::List(vec![Element::Integer(8),Element::List(vec![Element::List(vec![Element::Integer(6),Element::Integer(1),Element::Integer(3)]),Element::Integer(7)]),Element::Integer(4),Element::Integer(7),Element::List(vec![Element::List(vec![Element::Integer(10),Element::Integer(6)]),Element::List(vec![Element::Integer(4),Element::Integer(7),Element::Integer(10),Element::Integer(6),Element::Integer(8)]),Element::List(vec![Element::Integer(3),Element::Integer(4),Element::Integer(10),Element::Integer(4),Element::Integer(5)]),Element::List(vec![Element::Integer(0)]),Element::Integer(4)])]),Element::List(vec![Element::List(vec![Element::Integer(0)]),Element::List(vec![Element::Integer(2),Element::List(vec![Element::Integer(3)]),Element::List(vec![])]),Element::Integer(1)])]),
-        )
-    ]
+#[test]
+fn test_decoder_key_ignores_packets_equal_to_dividers() {
+    let text = "[1]\n[2]\n\n[[[2]]]\n[3]\n";
+    let packets: Vec<Element> = parse::parse_pairs(text.lines().map(String::from)).unwrap()
+        .into_iter()
+        .flat_map(|(left, right)| [left, right])
+        .collect();
+    // [1] sorts before [[2]], and [1], [2], [[[2]]] and [3] before [[6]]
+    assert_eq!(decoder_key(&packets), 2 * 6);
+    let arena = PacketArena::new(text.to_string()).unwrap();
+    assert_eq!(arena.decoder_key(), 2 * 6);
 }
-*/
\ No newline at end of file
@@ -0,0 +1,128 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::Element;
+
+/// something wrong with a packet, along with the (0 indexed) column it was noticed at
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: {}", self.position, self.message)
+    }
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: String) -> ParseError {
+        ParseError { position: self.position, message }
+    }
+
+    /// describes the next byte for error messages
+    fn found(&self) -> String {
+        match self.bytes.get(self.position) {
+            Some(byte) => format!("'{}'", *byte as char),
+            None => "the end of the packet".to_string(),
+        }
+    }
+
+    fn parse_element(&mut self) -> Result<Element, ParseError> {
+        match self.bytes.get(self.position) {
+            Some(b'[') => self.parse_list(),
+            Some(byte) if byte.is_ascii_digit() => self.parse_integer(),
+            _ => Err(self.error(format!("expected '[' or a digit, found {}", self.found()))),
+        }
+    }
+
+    fn parse_integer(&mut self) -> Result<Element, ParseError> {
+        let start = self.position;
+        while self.bytes.get(self.position).is_some_and(|byte| byte.is_ascii_digit()) {
+            self.position += 1;
+        }
+        let digits = std::str::from_utf8(&self.bytes[start..self.position]).expect("digits are ascii");
+        digits.parse()
+            .map(Element::Integer)
+            .map_err(|_| ParseError { position: start, message: format!("{} is too big", digits) })
+    }
+
+    fn parse_list(&mut self) -> Result<Element, ParseError> {
+        // skip the '['
+        self.position += 1;
+        let mut elements = Vec::new();
+        if self.bytes.get(self.position) == Some(&b']') {
+            self.position += 1;
+            return Ok(Element::List(elements));
+        }
+        loop {
+            elements.push(self.parse_element()?);
+            match self.bytes.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Element::List(elements));
+                },
+                _ => return Err(self.error(format!("expected ',' or ']', found {}", self.found()))),
+            }
+        }
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseError;
+
+    /// parses packet syntax like `[1,[2,[3]]]`, without any whitespace
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { bytes: s.as_bytes(), position: 0 };
+        let element = parser.parse_element()?;
+        match parser.position == s.len() {
+            true => Ok(element),
+            false => Err(parser.error(format!("expected the end of the packet, found {}", parser.found()))),
+        }
+    }
+}
+
+/// Reads pairs of packets separated by blank lines, with errors giving the (1 indexed) line.
+pub fn parse_pairs(lines: impl Iterator<Item = String>) -> Result<Vec<(Element, Element)>, String> {
+    let mut packets = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line = line.trim();
+        if !line.is_empty() {
+            let packet = line.parse().map_err(|err| format!("line {}, {}", i + 1, err))?;
+            packets.push(packet);
+        }
+    }
+    if packets.len() % 2 == 1 {
+        return Err(format!("{} packets can't be split into pairs", packets.len()));
+    }
+    let mut packets = packets.into_iter();
+    Ok(std::iter::from_fn(|| Some((packets.next()?, packets.next()?))).collect())
+}
+
+#[test]
+fn test_parse() {
+    use Element::{Integer, List};
+
+    let parsed = |s: &str| s.parse::<Element>().unwrap();
+    assert_eq!(parsed("[1,[2,[3]]]"), List(vec![Integer(1), List(vec![Integer(2), List(vec![Integer(3)])])]));
+    assert_eq!(parsed("[[],10]"), List(vec![List(vec![]), Integer(10)]));
+    assert_eq!(parsed("7"), Integer(7));
+
+    let error = |s: &str| s.parse::<Element>().unwrap_err();
+    assert_eq!(error("[1,,2]"), ParseError { position: 3, message: "expected '[' or a digit, found ','".to_string() });
+    assert_eq!(error("[1,[2]").position, 6);
+    assert_eq!(error("[1]]").message, "expected the end of the packet, found ']'");
+    assert_eq!(error("[1 2]").position, 2);
+    assert_eq!(error("[99999999999]").position, 1);
+
+    let lines = "[1]\n[2]\n\n[[]]\n[3]\n".lines().map(String::from);
+    assert_eq!(parse_pairs(lines).unwrap().len(), 2);
+    let lines = "[1]\n[2]\n\n[[]\n[3]\n".lines().map(String::from);
+    assert_eq!(parse_pairs(lines).unwrap_err(), "line 4, column 3: expected ',' or ']', found the end of the packet");
+}
@@ -6,8 +6,8 @@ mod day03;
 mod day04;
 mod day05;
 mod day06;
+mod day07;
 mod day08;
-//mod day07;
 mod day09;
 mod day10;
 mod day11;
@@ -18,8 +18,173 @@ mod day15;
 mod day16;
 mod day17;
 
+/// reports a failed tool run on stderr and exits with a failing status
+fn fail<E: std::fmt::Display>(err: E) -> ! {
+    eprintln!("{}", err);
+    std::process::exit(1);
+}
+
+/// extra tools for poking at one day's input, e.g. `cargo run -- day07 tree res/day07/input.sample`
+fn run_tool(args: &[&str]) {
+    match args {
+        ["day07", "tree", path] => print!("{}", day07::load(path).listing()),
+        ["day07", "du", path] => print!("{}", day07::load(path).du_report()),
+        ["day07", "under", max_size, path] => {
+            let max_size = utils::parse_usize(max_size).expect("max size should be a number");
+            for (dir, size) in day07::load(path).directories_at_most(max_size) {
+                println!("{}\t{}", size, dir);
+            }
+        },
+        ["day07", "free", min_size, path] => {
+            let min_size = utils::parse_usize(min_size).expect("min size should be a number");
+            match day07::load(path).smallest_directory_at_least(min_size) {
+                Some((dir, size)) => println!("{}\t{}", size, dir),
+                None => println!("no directory is that big"),
+            }
+        },
+        ["day07", "size", dir, path] => match day07::load(path).size_of(dir) {
+            Some(size) => println!("{}\t{}", size, dir),
+            None => println!("no such file or directory {}", dir),
+        },
+        ["day07", "check", path] => match day07::load_with_mode(path, day07::ReplayMode::Strict) {
+            Ok(_) => println!("transcript is consistent"),
+            Err(err) => fail(err),
+        },
+        ["day07", "check", "--lenient", path] => match day07::load_with_mode(path, day07::ReplayMode::Lenient) {
+            Ok(_) => println!("transcript is consistent"),
+            Err(err) => fail(err),
+        },
+        ["day07", "export", path, dest] => {
+            day07::load(path).materialize(std::path::Path::new(dest)).expect("couldn't write out the filesystem");
+        },
+        ["day07", "transcript", dir] => {
+            for line in day07::transcript_from_dir(std::path::Path::new(dir)).expect("couldn't read the directory") {
+                println!("{}", line);
+            }
+        },
+        ["day08", "visible", path] => print!("{}", day08::render_visibility(&day08::load(path))),
+        ["day08", "heatmap", path] => print!("{}", day08::render_heatmap(&day08::load(path))),
+        ["day08", "ppm", path, out_path] => {
+            day08::write_heatmap_ppm(&day08::load(path), out_path, 4).expect("couldn't write the heatmap");
+        },
+        ["day08", "top", k, path] => {
+            let k = utils::parse_usize(k).expect("k should be a number");
+            print!("{}", day08::top_scenic_spots(&day08::load(path), k));
+        },
+        ["day09", "render", num_knots, rule, mode, path, viewport @ ..] => {
+            let num_knots = utils::parse_usize(num_knots).expect("number of knots should be a number");
+            let rule: day09::FollowRule = rule.parse().unwrap_or_else(|err| panic!("{}", err));
+            let mode: day09::RenderMode = mode.parse().unwrap_or_else(|err| panic!("{}", err));
+            let viewport: Option<day09::Viewport> = viewport.first()
+                .map(|viewport| viewport.parse().unwrap_or_else(|err| panic!("{}", err)));
+            print!("{}", day09::render_simulation(path, num_knots, rule, mode, viewport).unwrap_or_else(|err| fail(err)));
+        },
+        ["day09", "visited", num_knots, rule, path] => {
+            let num_knots = utils::parse_usize(num_knots).expect("number of knots should be a number");
+            let rule: day09::FollowRule = rule.parse().unwrap_or_else(|err| panic!("{}", err));
+            let visited = day09::visited_per_knot(path, num_knots, rule).unwrap_or_else(|err| fail(err));
+            for (knot, visited) in visited.into_iter().enumerate() {
+                println!("{}\t{}", knot, visited);
+            }
+        },
+        ["day09", "fast", num_knots, rule, path] => {
+            let num_knots = utils::parse_usize(num_knots).expect("number of knots should be a number");
+            let rule: day09::FollowRule = rule.parse().unwrap_or_else(|err| panic!("{}", err));
+            println!("{}", day09::fast_num_tail_positions(path, num_knots, rule).unwrap_or_else(|err| fail(err)));
+        },
+        ["day09", "bench", num_motions, max_amount, num_knots] => {
+            let num_motions = utils::parse_usize(num_motions).expect("number of motions should be a number");
+            let max_amount = utils::parse_usize(max_amount).expect("max amount should be a number");
+            let num_knots = utils::parse_usize(num_knots).expect("number of knots should be a number");
+            print!("{}", day09::benchmark(num_motions, max_amount, num_knots).unwrap_or_else(|err| fail(err)));
+        },
+        ["day10", "trace", path] => {
+            print!("{}", day10::load(path).trace(day10::MAX_CYCLES).unwrap_or_else(|err| fail(err)));
+        },
+        ["day10", "asm", path] => match day10::assemble(utils::read_input(path)) {
+            Ok(program) => {
+                for (pc, instruction) in program.iter().enumerate() {
+                    println!("{}\t{}", pc, instruction);
+                }
+            },
+            Err(errors) => {
+                for err in &errors {
+                    eprintln!("{}", err);
+                }
+                fail(format!("{} assembly errors", errors.len()));
+            },
+        },
+        ["day10", "debug", path] => {
+            let stdin = std::io::stdin();
+            day10::Debugger::new(day10::load(path))
+                .run(stdin.lock(), &mut std::io::stdout())
+                .expect("couldn't talk to the terminal");
+        },
+        ["day10", "crt", width, height, sprite_width, format, path, max_frames @ ..] => {
+            let size = |arg: &str| utils::parse_usize(arg).expect("crt sizes should be numbers");
+            let format: day10::OutputFormat = format.parse().unwrap_or_else(|err| panic!("{}", err));
+            // the puzzle programs finish within one frame, this leaves room for longer ones
+            let max_frames = max_frames.first().map_or(100, |max_frames| size(max_frames));
+            let mut crt = day10::Crt::new(size(width), size(height), size(sprite_width));
+            if !day10::draw(path, &mut crt, max_frames) {
+                eprintln!("warning: program still running after {} frames, showing what was drawn", max_frames);
+            }
+            println!("{}", crt.render(format));
+        },
+        ["day11", "run", backend, rounds, relief, path] => {
+            let backend: day11::Backend = backend.parse().unwrap_or_else(|err| panic!("{}", err));
+            let rounds = utils::parse_usize(rounds).expect("rounds should be a number");
+            println!("{}", day11::simulate(path, rounds, *relief == "relief", backend).unwrap_or_else(|err| fail(err)));
+        },
+        ["day11", "verify", rounds, path] => {
+            let rounds = utils::parse_usize(rounds).expect("rounds should be a number");
+            match day11::verify_modular(path, rounds) {
+                Ok(report) => println!("{}", report),
+                Err(err) => fail(err),
+            }
+        },
+        ["day11", "extrapolate", rounds, path] => {
+            let rounds = rounds.parse().expect("rounds should be a number");
+            println!("{}", day11::extrapolate(path, rounds).unwrap_or_else(|err| fail(err)));
+        },
+        ["day11", "report", backend, rounds, relief, selection, format, path] => {
+            let backend: day11::Backend = backend.parse().unwrap_or_else(|err| panic!("{}", err));
+            let rounds = utils::parse_usize(rounds).expect("rounds should be a number");
+            let selection: day11::RoundSelection = selection.parse().unwrap_or_else(|err| panic!("{}", err));
+            let format: day11::ReportFormat = format.parse().unwrap_or_else(|err| panic!("{}", err));
+            let report = day11::report(path, rounds, *relief == "relief", backend, &selection, format);
+            print!("{}", report.unwrap_or_else(|err| fail(err)));
+        },
+        ["day13", "to-json", path] => println!("{}", day13::packets_to_json(path)),
+        ["day13", "from-json", path] => match day13::packets_from_json(path) {
+            Ok(packets) => println!("{}", packets),
+            Err(err) => fail(err),
+        },
+        ["day13", "explain", path] => print!("{}", day13::explain_pairs(path, None)),
+        ["day13", "explain", path, pair] => {
+            let pair = utils::parse_usize(pair).expect("pair should be a number");
+            print!("{}", day13::explain_pairs(path, Some(pair)));
+        },
+        ["day13", "flat", path] => {
+            let arena = day13::PacketArena::load(path).unwrap_or_else(|err| fail(err));
+            println!("part 1: {}\npart 2: {}", arena.sum_of_ordered_pairs(), arena.decoder_key());
+        },
+        ["day13", "bench", num_pairs, depth] => {
+            let num_pairs = utils::parse_usize(num_pairs).expect("num_pairs should be a number");
+            let depth = utils::parse_usize(depth).expect("depth should be a number");
+            print!("{}", day13::benchmark(num_pairs, depth));
+        },
+        _ => fail(format!("unknown command {:?}", args)),
+    }
+}
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+        run_tool(&args);
+        return;
+    }
     /*
     day01::answer_part_1("res/day01/part1.sample");
     day01::answer_part_1("res/day01/part1.real");
@@ -46,12 +211,12 @@ fn main() {
     println!("day06 part 1 real answer  : {:?}", day06::answer_part_1("res/day06/input.real"));
     println!("day06 part 2 sample answer: {:?}", day06::answer_part_2("res/day06/input.sample"));
     println!("day06 part 2 real answer  : {:?}", day06::answer_part_2("res/day06/input.real"));
-    //println!("day07 part 1 sample answer: {:?}", day07::answer_part_1("res/day07/input.sample"));
-    //println!("day07 part 1 real answer  : {:?}", day07::answer_part_1("res/day07/input.real"));
-    //println!("day07 part 2 sample answer: {:?}", day07::answer_part_2("res/day07/input.sample"));
-    //println!("day07 part 2 real answer  : {:?}", day07::answer_part_2("res/day07/input.real"));
+    println!("day07 part 1 sample answer: {:?}", day07::answer_part_1("res/day07/input.sample"));
+    println!("day07 part 1 real answer  : {:?}", day07::answer_part_1("res/day07/input.real"));
+    println!("day07 part 2 sample answer: {:?}", day07::answer_part_2("res/day07/input.sample"));
+    println!("day07 part 2 real answer  : {:?}", day07::answer_part_2("res/day07/input.real"));
     println!("day08 part 1 sample answer: {:?}", day08::answer_part_1("res/day08/input.sample"));
-    //println!("day08 part 1 real answer  : {:?}", day08::answer_part_1("res/day08/input.real"));
+    println!("day08 part 1 real answer  : {:?}", day08::answer_part_1("res/day08/input.real"));
     println!("day08 part 2 sample answer: {:?}", day08::answer_part_2("res/day08/input.sample"));
     println!("day08 part 2 real answer  : {:?}", day08::answer_part_2("res/day08/input.real"));
     println!("day09 part 1 sample answer: {:?}", day09::answer_part_1("res/day09/input.sample"));
@@ -64,6 +229,7 @@ fn main() {
     println!("day10 part 1 real answer  : {}", day10::answer_part_1("res/day10/input.real"));
     println!("day10 part 2 sample answer: \n{}", day10::answer_part_2("res/day10/input.sample"));
     println!("day10 part 2 real answer  : \n{}", day10::answer_part_2("res/day10/input.real"));
+    println!("day10 part 2 real letters : {}", day10::read_screen("res/day10/input.real").unwrap_or_else(|err| err.to_string()));
 
     println!("day11 part 1 sample answer: {}", day11::answer_part_1("res/day11/input.sample"));
     println!("day11 part 1 real answer  : {}", day11::answer_part_1("res/day11/input.real"));
@@ -75,10 +241,10 @@ fn main() {
     println!("day12 part 2 sample answer: {}", day12::answer_part_2("res/day12/input.sample"));
     println!("day12 part 2 real answer  : {}", day12::answer_part_2("res/day12/input.real"));
 
-    println!("day13 part 1 sample answer: {}", day13::answer_part_1(day13::data1()));
-    //println!("day13 part 1 real answer  : {}", day13::answer_part_1(day13::data2()));
-    println!("day13 part 2 sample answer: {}", day13::answer_part_2(day13::data1()));
-    //println!("day13 part 2 real answer  : {}", day13::answer_part_2(day13::data2()));
+    println!("day13 part 1 sample answer: {}", day13::answer_part_1("res/day13/input.sample"));
+    println!("day13 part 1 real answer  : {}", day13::answer_part_1("res/day13/input.real"));
+    println!("day13 part 2 sample answer: {}", day13::answer_part_2("res/day13/input.sample"));
+    println!("day13 part 2 real answer  : {}", day13::answer_part_2("res/day13/input.real"));
 
     println!("day14 part 1 sample answer: {}", day14::answer_part_1("res/day14/input.sample"));
     println!("day14 part 1 real answer  : {}", day14::answer_part_1("res/day14/input.real"));
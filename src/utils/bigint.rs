@@ -0,0 +1,265 @@
+use std::{cmp::Ordering, fmt::Display, ops::{Add, Div, Mul, Rem, Sub}};
+
+/// Arbitrary precision signed integer, stored as a sign and little endian base 2^32 limbs.
+/// The limbs never have trailing zeros, so zero is an empty, non-negative number.
+/// Division truncates towards zero like it does for the machine integers.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+/// compares two magnitudes
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = match a.len() >= b.len() { true => (a, b), false => (b, a) };
+    let mut out = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, limb) in long.iter().enumerate() {
+        let sum = *limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        out.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        out.push(carry as u32);
+    }
+    out
+}
+
+/// `a - b`, where `a` is at least as big as `b`
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, limb) in a.iter().enumerate() {
+        let mut diff = *limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = match diff < 0 { true => 1, false => 0 };
+        diff += borrow << 32;
+        out.push(diff as u32);
+    }
+    trim(out)
+}
+
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut out = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let product = *x as u64 * *y as u64 + out[i + j] as u64 + carry;
+            out[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        out[i + b.len()] = carry as u32;
+    }
+    trim(out)
+}
+
+/// quotient and remainder of `a / divisor` for a single limb divisor
+fn divrem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem = 0u64;
+    for (i, limb) in a.iter().enumerate().rev() {
+        let current = (rem << 32) | *limb as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        rem = current % divisor as u64;
+    }
+    (trim(quotient), rem as u32)
+}
+
+/// Quotient and remainder of two magnitudes. Anything with more than one limb in the
+/// divisor falls back to shift and subtract long division, one bit at a time.
+fn divrem_limbs(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if cmp_limbs(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if let [divisor] = b {
+        let (quotient, rem) = divrem_small(a, *divisor);
+        return (quotient, trim(vec![rem]));
+    }
+    let mut quotient = vec![0u32; a.len()];
+    let mut rem: Vec<u32> = Vec::new();
+    for bit in (0..a.len() * 32).rev() {
+        // rem = rem * 2 + next bit of a
+        rem = add_limbs(&rem, &rem);
+        if (a[bit / 32] >> (bit % 32)) & 1 == 1 {
+            rem = add_limbs(&rem, &[1]);
+        }
+        if cmp_limbs(&rem, b) != Ordering::Less {
+            rem = sub_limbs(&rem, b);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (trim(quotient), rem)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, limbs: Vec<u32>) -> Self {
+        let limbs = trim(limbs);
+        Self { negative: negative && !limbs.is_empty(), limbs }
+    }
+
+    pub fn zero() -> Self {
+        Self { negative: false, limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// the value as an `i64`, if it fits
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.limbs.len() > 2 {
+            return None;
+        }
+        let magnitude = self.limbs.iter().rev().fold(0u64, |acc, limb| (acc << 32) | *limb as u64);
+        match self.negative {
+            true => 0i64.checked_sub_unsigned(magnitude),
+            false => i64::try_from(magnitude).ok(),
+        }
+    }
+
+    /// `a + b` where the signs say whether each side is negative
+    fn signed_add(a_negative: bool, a: &[u32], b_negative: bool, b: &[u32]) -> Self {
+        if a_negative == b_negative {
+            return Self::from_parts(a_negative, add_limbs(a, b));
+        }
+        match cmp_limbs(a, b) {
+            Ordering::Less => Self::from_parts(b_negative, sub_limbs(b, a)),
+            _ => Self::from_parts(a_negative, sub_limbs(a, b)),
+        }
+    }
+
+    pub fn divrem(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, rem) = divrem_limbs(&self.limbs, &other.limbs);
+        (Self::from_parts(self.negative != other.negative, quotient), Self::from_parts(self.negative, rem))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        Self::from_parts(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        BigInt::signed_add(self.negative, &self.limbs, other.negative, &other.limbs)
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        BigInt::signed_add(self.negative, &self.limbs, !other.negative, &other.limbs)
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(self.negative != other.negative, mul_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+impl Div for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.divrem(other).0
+    }
+}
+
+impl Rem for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.divrem(other).1
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_limbs(&self.limbs, &other.limbs),
+            (true, true) => cmp_limbs(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        // peel off nine decimal digits at a time, least significant first
+        let mut chunks = Vec::new();
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (quotient, chunk) = divrem_small(&rest, CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut out = String::new();
+        if self.negative {
+            out.push('-');
+        }
+        out.push_str(&chunks.pop().expect("non-zero has a digit").to_string());
+        for chunk in chunks.iter().rev() {
+            out.push_str(&format!("{:09}", chunk));
+        }
+        f.write_str(&out)
+    }
+}
+
+#[test]
+fn test_bigint_matches_i128() {
+    // numbers of every size up to 63 bits
+    let mut lcg = super::lcg::Lcg::new(0x2022_0011);
+    let mut next = || {
+        let state = lcg.next_u64();
+        (state >> 1) as i64 >> (state % 60)
+    };
+    for _ in 0..2000 {
+        let sign = |negative: bool| match negative { true => -1, false => 1 };
+        let (a, b) = (next() * sign(next() % 2 == 0), next() * sign(next() % 3 == 0));
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+        let (a, b) = (a as i128, b as i128);
+        assert_eq!((&big_a + &big_b).to_string(), (a + b).to_string());
+        assert_eq!((&big_a - &big_b).to_string(), (a - b).to_string());
+        assert_eq!((&big_a * &big_b).to_string(), (a * b).to_string());
+        assert_eq!(big_a.cmp(&big_b), a.cmp(&b));
+        if b != 0 {
+            // divide something bigger than 64 bits so long division gets used too
+            let product = &big_a * &BigInt::from(a.abs() as i64);
+            assert_eq!((&product / &big_b).to_string(), (a * a.abs() / b).to_string());
+            assert_eq!((&product % &big_b).to_string(), (a * a.abs() % b).to_string());
+        }
+    }
+    assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!((&BigInt::from(i64::MAX) + &BigInt::from(1)).to_i64(), None);
+    assert_eq!(BigInt::zero().to_string(), "0");
+}
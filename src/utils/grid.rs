@@ -3,6 +3,19 @@ use std::{fmt::Display, iter::Enumerate};
 
 type Point = (usize, usize);
 
+/// Which way a scan along a row or column looks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl ScanDirection {
+    pub const ALL: [ScanDirection; 4] = [ScanDirection::Up, ScanDirection::Left, ScanDirection::Down, ScanDirection::Right];
+}
+
 #[derive(Debug)]
 pub struct Grid<T> {
     data: Vec<T>,
@@ -36,6 +49,19 @@ impl<T> Grid<T> {
         Self {height, width, data}
     }
 
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// every cell in row major order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
     fn check_row_col(&self, row: usize, col: usize) {
         match (row < self.height, col < self.width) {
             (true, true) => {},
@@ -88,5 +114,52 @@ impl<T> Grid<T> {
     pub fn get_yx_point_mut(&mut self, point: Point) -> &mut T {
         self.get_mut(point.0, point.1)
     }
+}
+
+impl<T: Ord> Grid<T> {
+    /// For every cell, finds the nearest cell looking in `direction` whose value is greater
+    /// than or equal to it. The answer is that cell's position along the line, so a column
+    /// for `Left`/`Right` and a row for `Up`/`Down`, or `None` if there's nothing that big
+    /// before the edge. Uses a monotonic stack, so it's one pass per row or column.
+    pub fn nearest_greater_or_equal(&self, direction: ScanDirection) -> Grid<Option<usize>> {
+        let mut result = vec![None; self.data.len()];
+        let (num_lines, line_len) = match direction {
+            ScanDirection::Left | ScanDirection::Right => (self.height, self.width),
+            ScanDirection::Up | ScanDirection::Down => (self.width, self.height),
+        };
+        // positions are visited starting from the side we're looking towards
+        let positions: Vec<usize> = match direction {
+            ScanDirection::Left | ScanDirection::Up => (0..line_len).collect(),
+            ScanDirection::Right | ScanDirection::Down => (0..line_len).rev().collect(),
+        };
+        let mut stack: Vec<usize> = Vec::with_capacity(line_len);
+        for line in 0..num_lines {
+            let ix = |pos: usize| match direction {
+                ScanDirection::Left | ScanDirection::Right => self.get_ix(line, pos),
+                ScanDirection::Up | ScanDirection::Down => self.get_ix(pos, line),
+            };
+            stack.clear();
+            for &pos in positions.iter() {
+                let value = &self.data[ix(pos)];
+                while stack.last().is_some_and(|top| &self.data[ix(*top)] < value) {
+                    stack.pop();
+                }
+                result[ix(pos)] = stack.last().copied();
+                stack.push(pos);
+            }
+        }
+        Grid { data: result, height: self.height, width: self.width }
+    }
+}
 
-}
\ No newline at end of file
+#[test]
+fn test_nearest_greater_or_equal() {
+    let grid = Grid::new_from_rows(vec![vec![2, 5, 5, 1, 2]]);
+    let left: Vec<_> = grid.nearest_greater_or_equal(ScanDirection::Left).iter().copied().collect();
+    assert_eq!(left, vec![None, None, Some(1), Some(2), Some(2)]);
+    let right: Vec<_> = grid.nearest_greater_or_equal(ScanDirection::Right).iter().copied().collect();
+    assert_eq!(right, vec![Some(1), Some(2), None, Some(4), None]);
+    let column = Grid::new_from_rows(vec![vec![3], vec![1], vec![3]]);
+    let up: Vec<_> = column.nearest_greater_or_equal(ScanDirection::Up).iter().copied().collect();
+    assert_eq!(up, vec![None, Some(0), Some(0)]);
+}
@@ -0,0 +1,272 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::AOCError;
+
+/// Just enough JSON to swap data with other tools. Objects keep their keys in order.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    f.write_str("\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl Display for Json {
+    /// compact JSON, without any whitespace
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str("]")
+            },
+            Json::Object(fields) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            },
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, expected: &str) -> AOCError {
+        AOCError::new(format!("expected {} at {}", expected, self.position))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    /// consumes `token` if it's next
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        let found = self.source[self.position..].starts_with(token);
+        if found {
+            self.position += token.len();
+        }
+        found
+    }
+
+    fn parse_value(&mut self) -> Result<Json, AOCError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') if self.eat("null") => Ok(Json::Null),
+            Some('t') if self.eat("true") => Ok(Json::Bool(true)),
+            Some('f') if self.eat("false") => Ok(Json::Bool(false)),
+            Some('"') => self.parse_string().map(Json::String),
+            Some('[') => {
+                self.position += 1;
+                let values = self.parse_sequence(']', Self::parse_value)?;
+                Ok(Json::Array(values))
+            },
+            Some('{') => {
+                self.position += 1;
+                let fields = self.parse_sequence('}', |parser| {
+                    parser.skip_whitespace();
+                    let key = parser.parse_string()?;
+                    match parser.eat(":") {
+                        true => Ok((key, parser.parse_value()?)),
+                        false => Err(parser.error("':'")),
+                    }
+                })?;
+                Ok(Json::Object(fields))
+            },
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    /// comma separated items up to `close`, with the opening bracket already consumed
+    fn parse_sequence<T>(&mut self, close: char, item: impl Fn(&mut Self) -> Result<T, AOCError>) -> Result<Vec<T>, AOCError> {
+        let mut items = Vec::new();
+        if self.eat(&close.to_string()) {
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            if self.eat(&close.to_string()) {
+                return Ok(items);
+            }
+            if !self.eat(",") {
+                return Err(self.error(&format!("',' or '{}'", close)));
+            }
+        }
+    }
+
+    /// consumes a run of digits and says how many there were
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    /// `-? (0 | [1-9][0-9]*) (. [0-9]+)? ([eE] [+-]? [0-9]+)?`, which is stricter than what
+    /// `f64` parses, e.g. `01`, `-.5` and `1.` aren't JSON
+    fn parse_number(&mut self) -> Result<Json, AOCError> {
+        let start = self.position;
+        self.eat("-");
+        match self.peek() {
+            Some('0') => self.position += 1,
+            Some('1'..='9') => { self.digits(); },
+            _ => return Err(self.error("a digit")),
+        }
+        if self.peek() == Some('.') {
+            self.position += 1;
+            if self.digits() == 0 {
+                return Err(self.error("a digit"));
+            }
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("a digit"));
+            }
+        }
+        self.source[start..self.position].parse()
+            .map(Json::Number)
+            .map_err(|_| AOCError::new(format!("bad number at {}: {}", start, &self.source[start..self.position])))
+    }
+
+    /// the four hex digits of a `\u` escape
+    fn parse_hex(&mut self) -> Result<u32, AOCError> {
+        let code = self.source.get(self.position..self.position + 4)
+            .filter(|hex| hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("four hex digits"))?;
+        self.position += 4;
+        Ok(code)
+    }
+
+    /// a `\u` escape, with the `\u` already consumed. Characters outside the basic plane
+    /// are written as a surrogate pair of two escapes, which get combined here.
+    fn parse_unicode_escape(&mut self) -> Result<char, AOCError> {
+        let code = match self.parse_hex()? {
+            high @ 0xD800..=0xDBFF => {
+                if !self.source[self.position..].starts_with("\\u") {
+                    return Err(self.error("the low half of a surrogate pair"));
+                }
+                self.position += 2;
+                match self.parse_hex()? {
+                    low @ 0xDC00..=0xDFFF => 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00),
+                    _ => return Err(self.error("the low half of a surrogate pair")),
+                }
+            },
+            code => code,
+        };
+        char::from_u32(code).ok_or_else(|| self.error("a character, not half a surrogate pair"))
+    }
+
+    fn parse_string(&mut self) -> Result<String, AOCError> {
+        if !self.eat("\"") {
+            return Err(self.error("a string"));
+        }
+        let mut out = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("'\"'"))?;
+            self.position += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("an escape"))?;
+                    self.position += 1;
+                    out.push(match escaped {
+                        '"' | '\\' | '/' => escaped,
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'u' => self.parse_unicode_escape()?,
+                        _ => return Err(self.error("an escape")),
+                    });
+                },
+                c => out.push(c),
+            }
+        }
+    }
+}
+
+impl FromStr for Json {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { source: s, position: 0 };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        match parser.position == s.len() {
+            true => Ok(value),
+            false => Err(parser.error("the end")),
+        }
+    }
+}
+
+#[test]
+fn test_json_round_trip() {
+    let text = r#" { "name" : "day \"13\"\n", "pairs": [ [1, [2.5, -3e2]], [] ], "ok": true, "none": null } "#;
+    let json: Json = text.parse().unwrap();
+    assert_eq!(json.to_string(), r#"{"name":"day \"13\"\n","pairs":[[1,[2.5,-300]],[]],"ok":true,"none":null}"#);
+    assert_eq!(json.to_string().parse::<Json>().unwrap(), json);
+    assert_eq!("\"\\u0041\"".parse::<Json>().unwrap(), Json::String("A".to_string()));
+    assert!("[1,]".parse::<Json>().unwrap_err().to_string().contains("expected a value at 3"));
+    assert!("[1] 2".parse::<Json>().is_err());
+
+    // characters outside the basic plane come as surrogate pairs
+    assert_eq!("\"\\ud83d\\ude00\"".parse::<Json>().unwrap(), Json::String("\u{1f600}".to_string()));
+    assert!("\"\\ud83d\"".parse::<Json>().is_err());
+    assert!("\"\\ude00\"".parse::<Json>().is_err());
+
+    assert_eq!("[0, -0.5, 10, 1E+2, 2e-1]".parse::<Json>().unwrap().to_string(), "[0,-0.5,10,100,0.2]");
+    for bad in ["-.5", "01", "1e+", "1.", ".5", "-", "+1", "1.e3"] {
+        assert!(bad.parse::<Json>().is_err(), "{}", bad);
+    }
+}
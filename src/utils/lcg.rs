@@ -0,0 +1,22 @@
+/// Linear congruential generator for synthetic inputs and tests that need to come out the
+/// same on every run. Nowhere near random enough for anything else.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// the whole new state; its low bits cycle quickly, so prefer `next` for small numbers
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.state
+    }
+
+    /// 31 bits taken from the top of the state, which are the most random ones
+    pub fn next(&mut self) -> usize {
+        (self.next_u64() >> 33) as usize
+    }
+}
@@ -1,4 +1,8 @@
+pub mod bigint;
 pub mod grid;
+pub mod json;
+pub mod lcg;
+pub mod tree;
 
 use std::{path::Path, fs::File, io::{self, BufRead}, fmt::Display};
 
@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+pub type NodeId = usize;
+
+/// Anything stored in a `Tree` has a weight, which the tree sums up over every subtree.
+pub trait Weighted {
+    fn weight(&self) -> usize;
+}
+
+#[derive(Debug)]
+struct Node<T> {
+    name: String,
+    value: T,
+    parent: Option<NodeId>,
+    children: BTreeMap<String, NodeId>,
+    subtree_weight: usize,
+}
+
+/// Arena backed tree with named children. Nodes are never removed, so a `NodeId`
+/// stays valid for the lifetime of the tree.
+#[derive(Debug)]
+pub struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: Weighted> Tree<T> {
+    pub fn new(root: T) -> Self {
+        let subtree_weight = root.weight();
+        let root = Node { name: "/".to_owned(), value: root, parent: None, children: BTreeMap::new(), subtree_weight };
+        Self { nodes: vec![root] }
+    }
+
+    pub fn root(&self) -> NodeId {
+        0
+    }
+
+    pub fn get(&self, id: NodeId) -> &T {
+        &self.nodes[id].value
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id].parent
+    }
+
+    pub fn child<S: AsRef<str>>(&self, id: NodeId, name: S) -> Option<NodeId> {
+        self.nodes[id].children.get(name.as_ref()).copied()
+    }
+
+    /// children of a node, in name order
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = (&str, NodeId)> {
+        self.nodes[id].children
+            .iter()
+            .map(|(name, id)| (name.as_str(), *id))
+    }
+
+    /// total weight of a node and everything below it. cached, so this is O(1)
+    pub fn subtree_weight(&self, id: NodeId) -> usize {
+        self.nodes[id].subtree_weight
+    }
+
+    /// Adds a child under `parent`. If a child with that name already exists it is
+    /// returned untouched and `value` is dropped.
+    pub fn add_child<S: AsRef<str>>(&mut self, parent: NodeId, name: S, value: T) -> NodeId {
+        if let Some(existing) = self.child(parent, name.as_ref()) {
+            return existing;
+        }
+        let id = self.nodes.len();
+        let name = name.as_ref().to_owned();
+        let weight = value.weight();
+        self.nodes[parent].children.insert(name.clone(), id);
+        self.nodes.push(Node { name, value, parent: Some(parent), children: BTreeMap::new(), subtree_weight: weight });
+        // keep the cached weights of every ancestor up to date
+        let mut ancestor = Some(parent);
+        while let Some(ix) = ancestor {
+            self.nodes[ix].subtree_weight += weight;
+            ancestor = self.nodes[ix].parent;
+        }
+        id
+    }
+
+    /// Resolves a `/` separated path. Absolute paths start at the root, relative ones
+    /// start at `from`. `.` and `..` work like they do in a shell, except that going
+    /// above the root gives `None`.
+    pub fn resolve<S: AsRef<str>>(&self, from: NodeId, path: S) -> Option<NodeId> {
+        let path = path.as_ref();
+        let start = match path.starts_with('/') {
+            true => self.root(),
+            false => from,
+        };
+        path.split('/')
+            .filter(|part| !part.is_empty() && *part != ".")
+            .try_fold(start, |id, part| match part {
+                ".." => self.parent(id),
+                name => self.child(id, name),
+            })
+    }
+
+    /// name of a node as seen from its parent; the root is called `/`
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.nodes[id].name
+    }
+
+    /// absolute path of a node, e.g. `/a/e`
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut curr = id;
+        while let Some(parent) = self.parent(curr) {
+            names.push(self.name(curr));
+            curr = parent;
+        }
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// every node id, parents before their children
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> {
+        0..self.nodes.len()
+    }
+}
+
+#[cfg(test)]
+impl Weighted for usize {
+    fn weight(&self) -> usize {
+        *self
+    }
+}
+
+#[test]
+fn test_resolve_and_subtree_weight() {
+    let mut tree: Tree<usize> = Tree::new(0);
+    let a = tree.add_child(tree.root(), "a", 0);
+    let e = tree.add_child(a, "e", 0);
+    tree.add_child(e, "i", 584);
+    tree.add_child(a, "f", 29116);
+    assert_eq!(tree.add_child(tree.root(), "a", 12), a);
+    assert_eq!(tree.resolve(e, "/a/e"), Some(e));
+    assert_eq!(tree.resolve(e, "../.."), Some(tree.root()));
+    assert_eq!(tree.resolve(tree.root(), ".."), None);
+    assert_eq!(tree.path(e), "/a/e");
+    assert_eq!(tree.name(e), "e");
+    assert_eq!(tree.name(tree.root()), "/");
+    assert_eq!(tree.subtree_weight(e), 584);
+    assert_eq!(tree.subtree_weight(tree.root()), 584 + 29116);
+}